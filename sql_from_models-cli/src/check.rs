@@ -0,0 +1,69 @@
+use super::generate::{indent, print_warnings, run_models};
+use super::opt::CheckOpt;
+use anyhow::{Error, Result};
+use console::style;
+use serde::*;
+use serde_json::from_str;
+
+#[derive(Serialize, Deserialize)]
+struct MigrationError {
+    kind: String,
+    message: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Output {
+    differences: Vec<(String, Vec<String>, Vec<String>)>,
+    #[serde(default)]
+    warnings: Vec<String>,
+    error: Option<MigrationError>,
+}
+
+impl Output {
+    fn print(self, schema_source: &str) -> Result<()> {
+        if let Some(err) = self.error {
+            return Err(Error::msg(err.message));
+        }
+        print_warnings(&self.warnings);
+        if self.differences.is_empty() {
+            println!("Everything is up to date.");
+            return Ok(());
+        }
+        for (name, statements, _) in &self.differences {
+            println!(
+                "{}: {}",
+                style("Changed").bold().yellow(),
+                style(name).cyan()
+            );
+            for stmt in statements {
+                println!("{};", style(indent(stmt)).dim());
+            }
+        }
+        Err(Error::msg(format!(
+            "{} table(s) differ from the {}. help: run `sql_from_models generate`.",
+            self.differences.len(),
+            schema_source
+        )))
+    }
+}
+
+pub async fn check(opt: CheckOpt) -> Result<()> {
+    let envs = [
+        ("MODELS_CHECK", "true".into()),
+        ("MODELS_FROM_DATABASE", opt.from_database.to_string()),
+        ("MODELS_UNSIGNED_CHECKS", opt.unsigned_checks.to_string()),
+    ];
+    let json = run_models(&opt.database_url, &opt.source, opt.table.as_deref(), &envs).await?;
+
+    if let Some(json) = json {
+        let schema_source = if opt.from_database {
+            "database"
+        } else {
+            "migrations"
+        };
+        from_str::<Output>(&json)
+            .expect(&json)
+            .print(schema_source)?;
+    }
+    Ok(())
+}
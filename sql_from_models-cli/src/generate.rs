@@ -1,8 +1,13 @@
 use super::opt::GenerateOpt;
-use anyhow::{Error, Result};
+use anyhow::{Context, Error, Result};
 use console::style;
 use serde::*;
-use serde_json::from_str;
+use serde_json::{from_str, Value};
+use sql_from_models::private::PROTOCOL_VERSION;
+use std::{
+    path::{Path, PathBuf},
+    process::Stdio,
+};
 
 #[derive(Serialize, Deserialize)]
 
@@ -13,11 +18,70 @@ struct MigrationError {
 #[derive(Serialize, Deserialize)]
 struct Output {
     success: Vec<(i64, String)>,
+    #[serde(default)]
+    warnings: Vec<String>,
     error: Option<MigrationError>,
 }
 
+#[derive(Serialize, Deserialize)]
+struct DryRunOutput {
+    differences: Vec<(String, Vec<String>, Vec<String>)>,
+    #[serde(default)]
+    warnings: Vec<String>,
+    error: Option<MigrationError>,
+}
+
+#[derive(Serialize)]
+struct PlannedTable<'a> {
+    table: &'a str,
+    up: &'a [String],
+    down: &'a [String],
+}
+
+impl DryRunOutput {
+    fn print(self, json: bool) -> Result<()> {
+        if let Some(err) = self.error {
+            return Err(Error::msg(err.message));
+        }
+        if json {
+            let tables: Vec<_> = self
+                .differences
+                .iter()
+                .map(|(table, up, down)| PlannedTable { table, up, down })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&tables)?);
+            return Ok(());
+        }
+        print_warnings(&self.warnings);
+        if self.differences.is_empty() {
+            println!("Everything is up to date.");
+        }
+        for (name, up, down) in &self.differences {
+            println!(
+                "{}: {}",
+                style("Planned").bold().green(),
+                style(name).cyan()
+            );
+            for stmt in up {
+                println!("{};", indent(stmt));
+            }
+            if !down.is_empty() {
+                println!("{}: {}", style("Down").bold().dim(), style(name).cyan());
+            }
+            for stmt in down {
+                println!("{};", indent(stmt));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Output {
     fn print(self, source: &str) -> Result<()> {
+        print_warnings(&self.warnings);
+        if self.success.is_empty() && self.error.is_none() {
+            println!("Everything is up to date.");
+        }
         for (num, name) in self.success {
             println!(
                 "{}: {}/{}{}{}",
@@ -38,89 +102,226 @@ impl Output {
 }
 
 pub async fn generate(opt: GenerateOpt) -> Result<()> {
-    use anyhow::*;
-    std::fs::create_dir_all(&opt.source).context("Unable to create migrations directory")?;
-    opt.validate().await?;
-    touch_any().await.ok();
-
-    if !builds(&opt.database_url, &opt.source).await {
-        return Err(Error::msg(
-            "could not compile project. No migrations were generated.",
-        ));
+    if !opt.dry_run {
+        std::fs::create_dir_all(&opt.source).context("Unable to create migrations directory")?;
     }
-    let filter_tests = format!(
-        "__models_generate_migration_{}",
-        opt.table.as_deref().unwrap_or("")
-    );
-    let output = tokio::process::Command::new("cargo")
-        .arg("test")
-        .arg("--")
-        .arg("--nocapture")
-        .arg(&filter_tests)
-        .env("MODELS_GENERATE_MIGRATIONS", "true")
-        .env("MIGRATIONS_DIR", &opt.source)
-        .env("DATABASE_URL", &opt.database_url)
-        .env("MODELS_GENERATE_DOWN", opt.reversible.to_string())
-        .output()
-        .await
-        .unwrap()
-        .stdout;
-    let output = String::from_utf8(output).unwrap();
-    let regex = regex::Regex::new("<SQLX-MODELS-OUTPUT>(.+)</SQLX-MODELS-OUTPUT>").unwrap();
-
-    if output.contains("running 0 tests") {
-        if let Some(table) = &opt.table {
-            println!("No sql_from_models named {}.", table)
-        } else {
-            println!("No sql_from_models in the application")
-        }
-        return Ok(());
+    if Path::new(&opt.source).exists() {
+        opt.validate().await?;
     }
-    let x = regex.captures(&output).expect(&output);
 
-    if let Some(json) = x.get(1) {
-        from_str::<Output>(json.as_str())
-            .expect(json.as_str())
-            .print(&opt.source)?;
-    } else {
-        println!("Everything is up to date.");
+    let envs = [
+        ("MODELS_GENERATE_DOWN", opt.reversible.to_string()),
+        ("MODELS_FROM_DATABASE", opt.from_database.to_string()),
+        ("MODELS_CHECK", opt.dry_run.to_string()),
+        ("MODELS_UNSIGNED_CHECKS", opt.unsigned_checks.to_string()),
+    ];
+    let json = run_models(&opt.database_url, &opt.source, opt.table.as_deref(), &envs).await?;
+
+    match json {
+        Some(json) if opt.dry_run => from_str::<DryRunOutput>(&json)
+            .expect(&json)
+            .print(opt.json)?,
+        Some(json) => from_str::<Output>(&json).expect(&json).print(&opt.source)?,
+        None => (),
     }
-    touch_any().await.ok();
     Ok(())
 }
 
-async fn builds(database_url: &str, source: &str) -> bool {
-    tokio::process::Command::new("cargo")
-        .arg("build")
-        .arg("--tests")
-        .env("MODELS_GENERATE_MIGRATIONS", "true")
-        .env("MIGRATIONS_DIR", database_url)
-        .env("DATABASE_URL", source)
-        .spawn()
-        .unwrap()
-        .wait()
+/// prints the warnings of the report, such as the foreign keys SQLite does not enforce by default.
+pub(crate) fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        println!("{}: {}", style("Warning").bold().yellow(), warning);
+    }
+}
+
+/// indents every line of a statement.
+pub(crate) fn indent(stmt: &str) -> String {
+    stmt.lines()
+        .map(|line| format!("    {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// runs the models of the project and returns the report they wrote.
+/// The models register themselves at link time, so the harness built by `build_harness`
+/// links every library of the workspace and generates their migrations when `MODELS_OUTPUT` is set.
+pub(crate) async fn run_models(
+    database_url: &str,
+    source: &str,
+    table: Option<&str>,
+    envs: &[(&str, String)],
+) -> Result<Option<String>> {
+    let executable = match build_harness().await? {
+        Some(executable) => executable,
+        None => {
+            println!("No sql_from_models in the application");
+            return Ok(None);
+        }
+    };
+    let output = std::env::temp_dir().join(format!("sql_from_models-{}.json", std::process::id()));
+    std::fs::remove_file(&output).ok();
+    let status = tokio::process::Command::new(&executable)
+        .env("MODELS_OUTPUT", &output)
+        .env("MIGRATIONS_DIR", source)
+        .env("DATABASE_URL", database_url)
+        .envs(table.map(|table| ("MODELS_TABLE", table)))
+        .envs(envs.iter().map(|(key, value)| (key, value)))
+        .stdout(Stdio::null())
+        .status()
         .await
-        .unwrap()
-        .success()
+        .with_context(|| format!("could not run {}", executable.display()))?;
+    if !output.exists() {
+        return Err(Error::msg(format!(
+            "{} exited with {} without a report.",
+            executable.display(),
+            status
+        )));
+    }
+    let json = std::fs::read_to_string(&output)?;
+    std::fs::remove_file(&output).ok();
+    let report: Value = from_str(&json)?;
+    let version = report["version"].as_u64();
+    if version != Some(PROTOCOL_VERSION) {
+        return Err(Error::msg(format!(
+            "the report has version {:?}, expected {}. help: use the same version of sql_from_models and sql_from_models-cli.",
+            version, PROTOCOL_VERSION
+        )));
+    }
+    if report["registered"].as_u64() == Some(0) {
+        match table {
+            Some(table) => println!("No sql_from_models named {}.", table),
+            None => println!("No sql_from_models in the application"),
+        }
+        return Ok(None);
+    }
+    Ok(Some(json))
 }
 
-pub async fn touch_any() -> Result<()> {
-    let mut listdir = tokio::fs::read_dir("src/").await?;
-    while let Some(entry) = listdir.next_entry().await? {
-        let file_name = entry.file_name();
-        let regex = regex::Regex::new(r".+\.rs")?;
-        if regex.is_match(file_name.to_str().unwrap()) {
-            // println!("{}", format!("src/{}", file_name.to_str().unwrap()));
-            let success = tokio::process::Command::new("touch")
-                .arg(&format!("src/{}", file_name.to_str().unwrap()))
-                .spawn()?
-                .wait()
-                .await?
-                .success();
-            if success {
-                break;
+/// builds the binary that generates the migrations and returns its path,
+/// or `None` if no library of the workspace depends on `sql_from_models`.
+/// The harness is a package written to the target directory that depends on those libraries,
+/// so their models are linked into it, and calls `sql_from_models::private::entry`.
+async fn build_harness() -> Result<Option<PathBuf>> {
+    let metadata = tokio::process::Command::new("cargo")
+        .arg("metadata")
+        .arg("--no-deps")
+        .arg("--format-version=1")
+        .stderr(Stdio::inherit())
+        .output()
+        .await?;
+    if !metadata.status.success() {
+        return Err(Error::msg("could not read the metadata of the project."));
+    }
+    let metadata: Value = from_str(&String::from_utf8(metadata.stdout)?)?;
+    let mut dependencies = vec![];
+    let mut models = None;
+    for package in metadata["packages"].as_array().into_iter().flatten() {
+        let dependency = package["dependencies"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|dep| dep["name"] == "sql_from_models" && dep["kind"].is_null());
+        let has_lib = package["targets"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .flat_map(|target| target["kind"].as_array().into_iter().flatten())
+            .any(|kind| ["lib", "rlib", "dylib"].contains(&kind.as_str().unwrap_or_default()));
+        let dependency = match dependency {
+            Some(dependency) if has_lib => dependency,
+            _ => continue,
+        };
+        models.get_or_insert_with(|| models_dependency(dependency));
+        let manifest = Path::new(package["manifest_path"].as_str().unwrap_or_default());
+        let dir = manifest.parent().unwrap_or(manifest);
+        dependencies.push(format!(
+            "lib{} = {{ path = {}, package = {} }}\n",
+            dependencies.len(),
+            Value::from(dir.to_string_lossy()),
+            package["name"]
+        ));
+    }
+    let models = match models {
+        Some(models) => models,
+        None => return Ok(None),
+    };
+    let crates: String = (0..dependencies.len())
+        .map(|i| format!("extern crate lib{};\n", i))
+        .collect();
+    // the lock file copied below resolves it to the version the libraries use.
+    dependencies.push(format!(
+        "sql_from_models = {{ {}, default-features = false }}\n",
+        models
+    ));
+
+    let target_dir = PathBuf::from(metadata["target_directory"].as_str().unwrap_or("target"));
+    let harness = target_dir.join("sql_from_models").join("harness");
+    std::fs::create_dir_all(harness.join("src"))?;
+    let manifest = format!(
+        "[package]\nname = \"sql_from_models_harness\"\nversion = \"0.0.0\"\nedition = \"2018\"\npublish = false\n\n[dependencies]\n{}\n[workspace]\n",
+        dependencies.concat()
+    );
+    let main = format!(
+        "{}\nfn main() {{\n    sql_from_models::private::entry();\n}}\n",
+        crates
+    );
+    write_if_changed(&harness.join("Cargo.toml"), &manifest)?;
+    write_if_changed(&harness.join("src").join("main.rs"), &main)?;
+    // the harness resolves the same versions as the workspace.
+    if let Some(root) = metadata["workspace_root"].as_str() {
+        let lock = Path::new(root).join("Cargo.lock");
+        if lock.exists() {
+            std::fs::copy(&lock, harness.join("Cargo.lock"))?;
+        }
+    }
+
+    let output = tokio::process::Command::new("cargo")
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(harness.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .arg("--message-format=json-render-diagnostics")
+        .stderr(Stdio::inherit())
+        .output()
+        .await?;
+    if !output.status.success() {
+        return Err(Error::msg(
+            "could not compile project. No migrations were generated.",
+        ));
+    }
+    let mut executable = None;
+    for line in String::from_utf8(output.stdout)?.lines() {
+        let message: Value = from_str(line)?;
+        if message["target"]["name"] == "sql_from_models_harness" {
+            if let Some(path) = message["executable"].as_str() {
+                executable = Some(PathBuf::from(path));
             }
         }
     }
+    executable
+        .map(Some)
+        .ok_or_else(|| Error::msg("could not find the executable of the harness."))
+}
+
+/// the source of `sql_from_models` in a manifest, as declared by a package of the workspace.
+fn models_dependency(dependency: &Value) -> String {
+    if let Some(path) = dependency["path"].as_str() {
+        return format!("path = {}", Value::from(path));
+    }
+    let source = dependency["source"].as_str().unwrap_or_default();
+    if let Some(git) = source.strip_prefix("git+") {
+        let url = git.split(['?', '#']).next().unwrap_or(git);
+        return format!("git = {}", Value::from(url));
+    }
+    let req = dependency["req"].as_str().unwrap_or("*");
+    format!("version = {}", Value::from(req))
+}
+
+/// writes a file of the harness unless it is up to date, so cargo does not rebuild it.
+fn write_if_changed(path: &Path, contents: &str) -> Result<()> {
+    if std::fs::read_to_string(path).ok().as_deref() != Some(contents) {
+        std::fs::write(path, contents)?;
+    }
     Ok(())
 }
@@ -1,8 +1,10 @@
 use anyhow::Result;
+use check::check;
 use generate::generate;
 
 use crate::opt::{Command, DatabaseCommand, MigrateCommand};
 
+mod check;
 mod database;
 
 mod generate;
@@ -34,6 +36,7 @@ pub async fn run(opt: Opt) -> Result<()> {
             MigrateCommand::BuildScript { force } => migrate::build_script(&migrate.source, force)?,
         },
         Command::Generate(gen_opt) => generate(gen_opt).await?,
+        Command::Check(check_opt) => check(check_opt).await?,
         Command::Database(database) => match database.command {
             DatabaseCommand::Create { database_url } => database::create(&database_url).await?,
             DatabaseCommand::Drop { yes, database_url } => {
@@ -18,6 +18,9 @@ pub enum Command {
 
     #[structopt(alias = "gen")]
     Generate(GenerateOpt),
+
+    #[structopt(alias = "diff")]
+    Check(CheckOpt),
 }
 
 /// Group of commands for creating and dropping your database.
@@ -95,12 +98,24 @@ pub struct GenerateOpt {
     /// Path to folder containing migrations.
     #[structopt(long, default_value = "migrations")]
     pub source: String,
-    /// Used to filter through the sql_from_models to execute.
+    /// Name of the structure of the model to generate. All of them are generated by default.
     #[structopt(long)]
     pub table: Option<String>,
     /// Used to generate a down migrations along with up migrations.
     #[structopt(short)]
     pub reversible: bool,
+    /// Compares the models against the tables of the database instead of the migrations folder.
+    #[structopt(long)]
+    pub from_database: bool,
+    /// Prints the statements that would be generated, grouped by table, without writing any file.
+    #[structopt(long)]
+    pub dry_run: bool,
+    /// Adds a `CHECK (col >= 0)` constraint to unsigned integer columns on PostgreSQL.
+    #[structopt(long)]
+    pub unsigned_checks: bool,
+    /// Prints the dry run as JSON.
+    #[structopt(long, requires = "dry-run")]
+    pub json: bool,
 }
 
 impl GenerateOpt {
@@ -117,6 +132,26 @@ impl GenerateOpt {
     }
 }
 
+/// Checks that the migrations are up to date with the models, without writing any file.
+#[derive(StructOpt, Debug)]
+pub struct CheckOpt {
+    /// Location of the DB, by default will be read from the DATABASE_URL env var
+    #[structopt(long, short = "D", env)]
+    pub database_url: String,
+    /// Path to folder containing migrations.
+    #[structopt(long, default_value = "migrations")]
+    pub source: String,
+    /// Name of the structure of the model to check. All of them are checked by default.
+    #[structopt(long)]
+    pub table: Option<String>,
+    /// Compares the models against the tables of the database instead of the migrations folder.
+    #[structopt(long)]
+    pub from_database: bool,
+    /// Adds a `CHECK (col >= 0)` constraint to unsigned integer columns on PostgreSQL.
+    #[structopt(long)]
+    pub unsigned_checks: bool,
+}
+
 #[derive(StructOpt, Debug)]
 pub enum MigrateCommand {
     /// Create a new migration with the given description,
@@ -11,7 +11,7 @@
 // limitations under the License.
 
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::fmt;
 
 #[cfg(feature = "serde")]
@@ -49,6 +49,22 @@ pub enum DataType {
     Int(Option<u64>),
     /// Big integer with optional display width e.g. BIGINT or BIGINT(20)
     BigInt(Option<u64>),
+    /// Unsigned tiny integer e.g. TINYINT UNSIGNED or TINYINT(3) UNSIGNED
+    ///
+    /// Note: this is a MySQL-specific type.
+    UnsignedTinyInt(Option<u64>),
+    /// Unsigned small integer e.g. SMALLINT UNSIGNED or SMALLINT(5) UNSIGNED
+    ///
+    /// Note: this is a MySQL-specific type.
+    UnsignedSmallInt(Option<u64>),
+    /// Unsigned integer e.g. INT UNSIGNED or INT(10) UNSIGNED
+    ///
+    /// Note: this is a MySQL-specific type.
+    UnsignedInt(Option<u64>),
+    /// Unsigned big integer e.g. BIGINT UNSIGNED or BIGINT(20) UNSIGNED
+    ///
+    /// Note: this is a MySQL-specific type.
+    UnsignedBigInt(Option<u64>),
     /// Floating point e.g. REAL
     Real,
     /// Double e.g. DOUBLE PRECISION
@@ -57,10 +73,10 @@ pub enum DataType {
     Boolean,
     /// Date
     Date,
-    /// Time
-    Time,
-    /// Timestamp
-    Timestamp,
+    /// Time, with a time zone if `true` e.g. TIME WITH TIME ZONE
+    Time(bool),
+    /// Timestamp, with a time zone if `true` e.g. TIMESTAMP WITH TIME ZONE
+    Timestamp(bool),
     /// Interval
     Interval,
     /// Regclass used in postgresql serial
@@ -73,6 +89,10 @@ pub enum DataType {
     Bytea,
     /// Custom type such as enums
     Custom(ObjectName),
+    /// Inline enum e.g. ENUM('a', 'b')
+    ///
+    /// Note: this is a MySQL-specific type.
+    Enum(Vec<String>),
     /// Arrays
     Array(Box<DataType>),
     /// JSON
@@ -122,12 +142,30 @@ impl fmt::Display for DataType {
                 }
             }
             DataType::BigInt(zerofill) => format_type_with_optional_length(f, "BIGINT", zerofill),
+            DataType::UnsignedTinyInt(zerofill) => {
+                format_type_with_optional_length(f, "TINYINT", zerofill)?;
+                write!(f, " UNSIGNED")
+            }
+            DataType::UnsignedSmallInt(zerofill) => {
+                format_type_with_optional_length(f, "SMALLINT", zerofill)?;
+                write!(f, " UNSIGNED")
+            }
+            DataType::UnsignedInt(zerofill) => {
+                format_type_with_optional_length(f, "INT", zerofill)?;
+                write!(f, " UNSIGNED")
+            }
+            DataType::UnsignedBigInt(zerofill) => {
+                format_type_with_optional_length(f, "BIGINT", zerofill)?;
+                write!(f, " UNSIGNED")
+            }
             DataType::Real => write!(f, "REAL"),
             DataType::Double => write!(f, "DOUBLE PRECISION"),
             DataType::Boolean => write!(f, "BOOLEAN"),
             DataType::Date => write!(f, "DATE"),
-            DataType::Time => write!(f, "TIME"),
-            DataType::Timestamp => write!(f, "TIMESTAMP"),
+            DataType::Time(false) => write!(f, "TIME"),
+            DataType::Time(true) => write!(f, "TIMETZ"),
+            DataType::Timestamp(false) => write!(f, "TIMESTAMP"),
+            DataType::Timestamp(true) => write!(f, "TIMESTAMPTZ"),
             DataType::Interval => write!(f, "INTERVAL"),
             DataType::Regclass => write!(f, "REGCLASS"),
             DataType::Text => write!(f, "TEXT"),
@@ -135,6 +173,16 @@ impl fmt::Display for DataType {
             DataType::Bytea => write!(f, "BYTEA"),
             DataType::Array(ty) => write!(f, "{}[]", ty),
             DataType::Custom(ty) => write!(f, "{}", ty),
+            DataType::Enum(labels) => {
+                write!(f, "ENUM(")?;
+                for (i, label) in labels.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "'{}'", super::value::escape_single_quote_string(label))?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
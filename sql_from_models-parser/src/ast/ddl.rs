@@ -20,6 +20,7 @@ use core::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::ast::value::escape_single_quote_string;
 use crate::ast::{display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName};
 use crate::tokenizer::Token;
 
@@ -37,6 +38,10 @@ pub enum AlterTableOperation {
         cascade: bool,
         restrict: bool,
     },
+    /// `DROP INDEX <name>`
+    ///
+    /// Note: this is a MySQL-specific operation.
+    DropIndex { name: Ident },
     /// `DROP [ COLUMN ] [ IF EXISTS ] <column_name> [ CASCADE ]`
     DropColumn {
         column_name: Ident,
@@ -64,6 +69,64 @@ pub enum AlterTableOperation {
     },
     /// `RENAME TO <table_name>`
     RenameTable { table_name: ObjectName },
+    /// `ALTER [ COLUMN ] <column_name> <operation>`
+    AlterColumn {
+        column_name: Ident,
+        op: AlterColumnOperation,
+    },
+    /// `MODIFY [ COLUMN ] <column_def>`
+    ///
+    /// Note: this is a MySQL-specific operation.
+    ModifyColumn { column_def: ColumnDef },
+    /// `COMMENT = '<comment>'`
+    ///
+    /// Note: this is a MySQL-specific operation.
+    SetComment { comment: String },
+}
+
+/// An `ALTER COLUMN` (`Statement::AlterTable`) operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlterColumnOperation {
+    /// `SET NOT NULL`
+    SetNotNull,
+    /// `DROP NOT NULL`
+    DropNotNull,
+    /// `SET DEFAULT <expr>`
+    SetDefault { value: Expr },
+    /// `DROP DEFAULT`
+    DropDefault,
+    /// `[ SET DATA ] TYPE <data_type> [ USING <expr> ]`
+    SetDataType {
+        data_type: DataType,
+        using: Option<Expr>,
+    },
+}
+
+/// An `ALTER TYPE` (`Statement::AlterType`) operation
+///
+/// Note: this is a PostgreSQL-specific operation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlterTypeOperation {
+    /// `ADD VALUE [ IF NOT EXISTS ] '<value>' [ { BEFORE | AFTER } '<neighbor>' ]`
+    AddValue {
+        if_not_exists: bool,
+        value: String,
+        position: Option<EnumValuePosition>,
+    },
+    /// `RENAME TO <new_name>`
+    RenameTo { new_name: Ident },
+    /// `RENAME VALUE '<from>' TO '<to>'`
+    RenameValue { from: String, to: String },
+}
+
+/// Position of a value added with `ALTER TYPE ... ADD VALUE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EnumValuePosition {
+    Before(String),
+    After(String),
 }
 
 impl fmt::Display for AlterTableOperation {
@@ -104,6 +167,7 @@ impl fmt::Display for AlterTableOperation {
                     write!(f, "DROP CONSTRAINT {}", name)
                 }
             }
+            AlterTableOperation::DropIndex { name } => write!(f, "DROP INDEX {}", name),
             AlterTableOperation::DropColumn {
                 column_name,
                 if_exists,
@@ -135,6 +199,76 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::RenameTable { table_name } => {
                 write!(f, "RENAME TO {}", table_name)
             }
+            AlterTableOperation::AlterColumn { column_name, op } => {
+                write!(f, "ALTER COLUMN {} {}", column_name, op)
+            }
+            AlterTableOperation::ModifyColumn { column_def } => {
+                write!(f, "MODIFY COLUMN {}", column_def)
+            }
+            AlterTableOperation::SetComment { comment } => {
+                write!(f, "COMMENT = '{}'", escape_single_quote_string(comment))
+            }
+        }
+    }
+}
+
+impl fmt::Display for AlterColumnOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterColumnOperation::SetNotNull => write!(f, "SET NOT NULL"),
+            AlterColumnOperation::DropNotNull => write!(f, "DROP NOT NULL"),
+            AlterColumnOperation::SetDefault { value } => write!(f, "SET DEFAULT {}", value),
+            AlterColumnOperation::DropDefault => write!(f, "DROP DEFAULT"),
+            AlterColumnOperation::SetDataType { data_type, using } => {
+                write!(f, "SET DATA TYPE {}", data_type)?;
+                if let Some(expr) = using {
+                    write!(f, " USING {}", expr)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for AlterTypeOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterTypeOperation::AddValue {
+                if_not_exists,
+                value,
+                position,
+            } => {
+                write!(
+                    f,
+                    "ADD VALUE {ine}'{value}'",
+                    ine = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    value = escape_single_quote_string(value)
+                )?;
+                if let Some(position) = position {
+                    write!(f, " {}", position)?;
+                }
+                Ok(())
+            }
+            AlterTypeOperation::RenameTo { new_name } => write!(f, "RENAME TO {}", new_name),
+            AlterTypeOperation::RenameValue { from, to } => write!(
+                f,
+                "RENAME VALUE '{}' TO '{}'",
+                escape_single_quote_string(from),
+                escape_single_quote_string(to)
+            ),
+        }
+    }
+}
+
+impl fmt::Display for EnumValuePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EnumValuePosition::Before(value) => {
+                write!(f, "BEFORE '{}'", escape_single_quote_string(value))
+            }
+            EnumValuePosition::After(value) => {
+                write!(f, "AFTER '{}'", escape_single_quote_string(value))
+            }
         }
     }
 }
@@ -174,7 +308,7 @@ pub struct Check {
 /// REFERENCES <foreign_table> (<referred_columns>)
 /// { [ON DELETE <referential_action>] [ON UPDATE <referential_action>] |
 ///   [ON UPDATE <referential_action>] [ON DELETE <referential_action>]
-/// }`).
+/// } [ [NOT] DEFERRABLE ] [ INITIALLY { DEFERRED | IMMEDIATE } ]`).
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ForeignKey {
     pub name: Option<Ident>,
@@ -183,6 +317,8 @@ pub struct ForeignKey {
     pub referred_columns: Vec<Ident>,
     pub on_delete: Option<ReferentialAction>,
     pub on_update: Option<ReferentialAction>,
+    /// `None` when the constraint is `NOT DEFERRABLE`, the default.
+    pub deferrable: Option<Deferrable>,
 }
 
 impl fmt::Display for TableConstraint {
@@ -206,6 +342,7 @@ impl fmt::Display for TableConstraint {
                 referred_columns,
                 on_delete,
                 on_update,
+                deferrable,
             }) => {
                 write!(
                     f,
@@ -221,6 +358,9 @@ impl fmt::Display for TableConstraint {
                 if let Some(action) = on_update {
                     write!(f, " ON UPDATE {}", action)?;
                 }
+                if let Some(deferrable) = deferrable {
+                    write!(f, " {}", deferrable)?;
+                }
                 Ok(())
             }
             TableConstraint::Check(Check { name, expr }) => {
@@ -305,6 +445,12 @@ pub enum ColumnOption {
     },
     /// `CHECK (<expr>)`
     Check(Expr),
+    /// `[ GENERATED ALWAYS ] AS (<expr>) [ STORED | VIRTUAL ]`
+    Generated { expr: Expr, stored: bool },
+    /// `COMMENT '<comment>'`
+    ///
+    /// Note: this is a MySQL-specific option.
+    Comment(String),
     /// Dialect-specific options, such as:
     /// - MySQL's `AUTO_INCREMENT` or SQLite's `AUTOINCREMENT`
     /// - ...
@@ -340,6 +486,13 @@ impl fmt::Display for ColumnOption {
                 Ok(())
             }
             Check(expr) => write!(f, "CHECK ({})", expr),
+            Generated { expr, stored } => write!(
+                f,
+                "GENERATED ALWAYS AS ({}) {}",
+                expr,
+                if *stored { "STORED" } else { "VIRTUAL" }
+            ),
+            Comment(comment) => write!(f, "COMMENT '{}'", escape_single_quote_string(comment)),
             DialectSpecific(val) => write!(f, "{}", display_separated(val, " ")),
         }
     }
@@ -383,3 +536,22 @@ impl fmt::Display for ReferentialAction {
         })
     }
 }
+
+/// `DEFERRABLE [ INITIALLY { DEFERRED | IMMEDIATE } ]`
+///
+/// Used in foreign key constraints to delay their check until the end of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Deferrable {
+    InitiallyImmediate,
+    InitiallyDeferred,
+}
+
+impl fmt::Display for Deferrable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Deferrable::InitiallyImmediate => "DEFERRABLE",
+            Deferrable::InitiallyDeferred => "DEFERRABLE INITIALLY DEFERRED",
+        })
+    }
+}
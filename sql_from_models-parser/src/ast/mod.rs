@@ -35,7 +35,7 @@ use serde::{Deserialize, Serialize};
 
 pub use self::data_type::DataType;
 pub use self::ddl::{
-    AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef, ReferentialAction,
+    AlterColumnOperation, AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef, Deferrable, ReferentialAction,
     TableConstraint,
 };
 pub use self::operator::{BinaryOperator, UnaryOperator};
@@ -145,6 +145,12 @@ impl fmt::Display for Ident {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ObjectName(pub Vec<Ident>);
 
+impl From<&str> for ObjectName {
+    fn from(value: &str) -> Self {
+        ObjectName(vec![value.into()])
+    }
+}
+
 impl fmt::Display for ObjectName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", display_separated(&self.0, "."))
@@ -470,6 +476,7 @@ pub enum ObjectType {
     View,
     Index,
     Schema,
+    Type,
 }
 
 impl fmt::Display for ObjectType {
@@ -479,6 +486,7 @@ impl fmt::Display for ObjectType {
             ObjectType::View => "VIEW",
             ObjectType::Index => "INDEX",
             ObjectType::Schema => "SCHEMA",
+            ObjectType::Type => "TYPE",
         })
     }
 }
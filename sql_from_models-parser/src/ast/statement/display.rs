@@ -19,6 +19,9 @@ impl fmt::Display for Statement {
             CreateVirtualTable(x) => write!(f, "{}", x),
             CreateIndex(x) => write!(f, "{}", x),
             AlterTable(x) => write!(f, "{}", x),
+            CreateType(x) => write!(f, "{}", x),
+            AlterType(x) => write!(f, "{}", x),
+            Comment(x) => write!(f, "{}", x),
             SetVariable(x) => write!(f, "{}", x),
             ShowVariable(x) => write!(f, "{}", x),
             ShowCreate(x) => write!(f, "{}", x),
@@ -257,6 +260,7 @@ impl fmt::Display for CreateTable {
         let query = &self.query;
         let without_rowid = &self.without_rowid;
         let like = &self.like;
+        let comment = &self.comment;
         write!(
             f,
             "CREATE {or_replace}{external}{temporary}TABLE {if_not_exists}{name}",
@@ -280,6 +284,14 @@ impl fmt::Display for CreateTable {
         if *without_rowid {
             write!(f, " WITHOUT ROWID")?;
         }
+        // Only for MySQL
+        if let Some(comment) = comment {
+            write!(
+                f,
+                " COMMENT = '{}'",
+                value::escape_single_quote_string(comment)
+            )?;
+        }
 
         // Only for Hive
         if let Some(l) = like {
@@ -420,6 +432,47 @@ impl fmt::Display for AlterTable {
     }
 }
 
+impl fmt::Display for CreateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let labels = self
+            .labels
+            .iter()
+            .map(|label| format!("'{}'", value::escape_single_quote_string(label)))
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "CREATE TYPE {} AS ENUM ({})",
+            self.name,
+            display_comma_separated(&labels)
+        )
+    }
+}
+
+impl fmt::Display for AlterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALTER TYPE {} {}", self.name, self.operation)
+    }
+}
+
+impl fmt::Display for Comment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "COMMENT ON {} {} IS ", self.object_type, self.name)?;
+        match &self.comment {
+            Some(comment) => write!(f, "'{}'", value::escape_single_quote_string(comment)),
+            None => write!(f, "NULL"),
+        }
+    }
+}
+
+impl fmt::Display for CommentObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CommentObject::Table => "TABLE",
+            CommentObject::Column => "COLUMN",
+        })
+    }
+}
+
 impl fmt::Display for Drop {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
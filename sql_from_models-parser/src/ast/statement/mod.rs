@@ -33,6 +33,18 @@ pub enum Statement {
     CreateIndex(CreateIndex),
     /// ALTER TABLE
     AlterTable(AlterTable),
+    /// `CREATE TYPE <name> AS ENUM (...)`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    CreateType(CreateType),
+    /// ALTER TYPE
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    AlterType(AlterType),
+    /// `COMMENT ON { TABLE | COLUMN } <name> IS { '<comment>' | NULL }`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    Comment(Comment),
     /// DROP
     Drop(Drop),
     /// SET <variable>
@@ -204,6 +216,8 @@ pub struct CreateTable {
     pub query: Option<Box<Query>>,
     pub without_rowid: bool,
     pub like: Option<ObjectName>,
+    /// MySQL's `COMMENT = '<comment>'`
+    pub comment: Option<String>,
 }
 /// SQLite's `CREATE VIRTUAL TABLE .. USING <module_name> (<module_args>)`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -233,6 +247,38 @@ pub struct AlterTable {
     pub name: ObjectName,
     pub operation: AlterTableOperation,
 }
+/// `CREATE TYPE <name> AS ENUM ( [ '<label>' [, ...] ] )`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CreateType {
+    pub name: ObjectName,
+    /// The values of the enum, in order.
+    pub labels: Vec<String>,
+}
+/// ALTER TYPE
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AlterType {
+    pub name: ObjectName,
+    pub operation: AlterTypeOperation,
+}
+/// `COMMENT ON { TABLE | COLUMN } <name> IS { '<comment>' | NULL }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Comment {
+    pub object_type: CommentObject,
+    /// The table, or the column qualified by its table.
+    pub name: ObjectName,
+    /// `None` removes the comment.
+    pub comment: Option<String>,
+}
+/// The kind of object a comment is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CommentObject {
+    Table,
+    Column,
+}
 /// DROP
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
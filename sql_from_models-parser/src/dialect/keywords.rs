@@ -70,9 +70,11 @@ define_keywords!(
     ABS,
     ACTION,
     ADD,
+    AFTER,
     ALL,
     ALLOCATE,
     ALTER,
+    ALWAYS,
     ANALYZE,
     AND,
     ANY,
@@ -93,6 +95,7 @@ define_keywords!(
     AUTO_INCREMENT,
     AVG,
     AVRO,
+    BEFORE,
     BEGIN,
     BEGIN_FRAME,
     BEGIN_PARTITION,
@@ -129,6 +132,7 @@ define_keywords!(
     COLLECT,
     COLUMN,
     COLUMNS,
+    COMMENT,
     COMMIT,
     COMMITTED,
     COMPUTE,
@@ -162,6 +166,7 @@ define_keywords!(
     CURRENT_USER,
     CURSOR,
     CYCLE,
+    DATA,
     DATABASE,
     DATE,
     DAY,
@@ -170,6 +175,8 @@ define_keywords!(
     DECIMAL,
     DECLARE,
     DEFAULT,
+    DEFERRABLE,
+    DEFERRED,
     DELETE,
     DELIMITED,
     DENSE_RANK,
@@ -191,6 +198,7 @@ define_keywords!(
     END_EXEC = "END-EXEC",
     END_FRAME,
     END_PARTITION,
+    ENUM,
     EQUALS,
     ERROR,
     ESCAPE,
@@ -224,6 +232,7 @@ define_keywords!(
     FULL,
     FUNCTION,
     FUSION,
+    GENERATED,
     GET,
     GLOBAL,
     GRANT,
@@ -239,9 +248,11 @@ define_keywords!(
     IF,
     IGNORE,
     ILIKE,
+    IMMEDIATE,
     IN,
     INDEX,
     INDICATOR,
+    INITIALLY,
     INNER,
     INOUT,
     INPUTFORMAT,
@@ -291,6 +302,7 @@ define_keywords!(
     MINUTE,
     MOD,
     MODIFIES,
+    MODIFY,
     MODULE,
     MONTH,
     MSCK,
@@ -446,6 +458,8 @@ define_keywords!(
     TIES,
     TIME,
     TIMESTAMP,
+    TIMESTAMPTZ,
+    TIMETZ,
     TIMEZONE_HOUR,
     TIMEZONE_MINUTE,
     TINYINT,
@@ -463,6 +477,7 @@ define_keywords!(
     TRUE,
     TRUNCATE,
     TRY_CAST,
+    TYPE,
     UESCAPE,
     UNBOUNDED,
     UNCOMMITTED,
@@ -470,6 +485,7 @@ define_keywords!(
     UNIQUE,
     UNKNOWN,
     UNNEST,
+    UNSIGNED,
     UPDATE,
     UPPER,
     USER,
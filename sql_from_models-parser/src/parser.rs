@@ -155,7 +155,11 @@ impl<'a> Parser<'a> {
                 Keyword::DELETE => Ok(Delete(self.parse_delete()?)),
                 Keyword::INSERT => Ok(self.parse_insert()?),
                 Keyword::UPDATE => Ok(Update(self.parse_update()?)),
+                Keyword::ALTER if self.parse_keyword(Keyword::TYPE) => {
+                    Ok(AlterType(self.parse_alter_type()?))
+                }
                 Keyword::ALTER => Ok(AlterTable(self.parse_alter()?)),
+                Keyword::COMMENT => Ok(Comment(self.parse_comment()?)),
                 Keyword::COPY => Ok(Copy(self.parse_copy()?)),
                 Keyword::SET => Ok(self.parse_set()?),
                 Keyword::SHOW => Ok(self.parse_show()?),
@@ -1294,6 +1298,8 @@ impl<'a> Parser<'a> {
             Ok(CreateVirtualTable(self.parse_create_virtual_table()?))
         } else if self.parse_keyword(Keyword::SCHEMA) {
             Ok(CreateSchema(self.parse_create_schema()?))
+        } else if self.parse_keyword(Keyword::TYPE) {
+            Ok(CreateType(self.parse_create_type()?))
         } else {
             self.expected("an object type after CREATE", self.peek_token())
         }
@@ -1328,6 +1334,25 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// PostgreSQL-specific `CREATE TYPE <name> AS ENUM (...)`
+    pub fn parse_create_type(&mut self) -> Result<CreateType, ParserError> {
+        let name = self.parse_object_name()?;
+        self.expect_keywords(&[Keyword::AS, Keyword::ENUM])?;
+        let labels = self.parse_enum_labels()?;
+        Ok(CreateType { name, labels })
+    }
+
+    /// parses the parenthesized labels of an enum, which may be empty.
+    fn parse_enum_labels(&mut self) -> Result<Vec<String>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        if self.consume_token(&Token::RParen) {
+            return Ok(vec![]);
+        }
+        let labels = self.parse_comma_separated(Parser::parse_literal_string)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(labels)
+    }
+
     pub fn parse_create_database(&mut self) -> Result<CreateDatabase, ParserError> {
         let ine = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let db_name = self.parse_object_name()?;
@@ -1389,6 +1414,7 @@ impl<'a> Parser<'a> {
             query: None,
             without_rowid: false,
             like: None,
+            comment: None,
         })
     }
 
@@ -1438,8 +1464,13 @@ impl<'a> Parser<'a> {
             ObjectType::Index
         } else if self.parse_keyword(Keyword::SCHEMA) {
             ObjectType::Schema
+        } else if self.parse_keyword(Keyword::TYPE) {
+            ObjectType::Type
         } else {
-            return self.expected("TABLE, VIEW, INDEX or SCHEMA after DROP", self.peek_token());
+            return self.expected(
+                "TABLE, VIEW, INDEX, SCHEMA or TYPE after DROP",
+                self.peek_token(),
+            );
         };
         // Many dialects support the non standard `IF EXISTS` clause and allow
         // specifying multiple objects to delete in a single statement
@@ -1551,6 +1582,14 @@ impl<'a> Parser<'a> {
         // SQLite supports `WITHOUT ROWID` at the end of `CREATE TABLE`
         let without_rowid = self.parse_keywords(&[Keyword::WITHOUT, Keyword::ROWID]);
 
+        // MySQL supports `COMMENT [=] '<comment>'` after the columns
+        let comment = if self.parse_keyword(Keyword::COMMENT) {
+            let _ = self.consume_token(&Token::Eq);
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+
         let hive_distribution = self.parse_hive_distribution()?;
         let hive_formats = self.parse_hive_formats()?;
         // PostgreSQL supports `WITH ( options )`, before `AS`
@@ -1580,6 +1619,7 @@ impl<'a> Parser<'a> {
             query,
             without_rowid,
             like,
+            comment,
         })
     }
 
@@ -1684,6 +1724,18 @@ impl<'a> Parser<'a> {
             let expr = self.parse_expr()?;
             self.expect_token(&Token::RParen)?;
             Ok(Some(ColumnOption::Check(expr)))
+        } else if self.parse_keywords(&[Keyword::GENERATED, Keyword::ALWAYS, Keyword::AS])
+            || self.parse_keyword(Keyword::AS)
+        {
+            self.expect_token(&Token::LParen)?;
+            let expr = self.parse_expr()?;
+            self.expect_token(&Token::RParen)?;
+            // MySQL and SQLite default to virtual columns
+            let stored = self.parse_one_of_keywords(&[Keyword::STORED, Keyword::VIRTUAL])
+                == Some(Keyword::STORED);
+            Ok(Some(ColumnOption::Generated { expr, stored }))
+        } else if self.parse_keyword(Keyword::COMMENT) {
+            Ok(Some(ColumnOption::Comment(self.parse_literal_string()?)))
         } else if self.parse_keyword(Keyword::AUTO_INCREMENT)
             && dialect_of!(self is MySqlDialect |  GenericDialect)
         {
@@ -1722,6 +1774,29 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses `[ [NOT] DEFERRABLE ] [ INITIALLY { DEFERRED | IMMEDIATE } ]`
+    pub fn parse_deferrable(&mut self) -> Result<Option<Deferrable>, ParserError> {
+        let deferrable = if self.parse_keywords(&[Keyword::NOT, Keyword::DEFERRABLE]) {
+            Some(false)
+        } else if self.parse_keyword(Keyword::DEFERRABLE) {
+            Some(true)
+        } else {
+            None
+        };
+        let initially_deferred = self.parse_keyword(Keyword::INITIALLY)
+            && self.expect_one_of_keywords(&[Keyword::DEFERRED, Keyword::IMMEDIATE])?
+                == Keyword::DEFERRED;
+        Ok(match (deferrable, initially_deferred) {
+            (Some(false), true) => {
+                return parser_err!("a NOT DEFERRABLE constraint cannot be INITIALLY DEFERRED")
+            }
+            // INITIALLY DEFERRED implies DEFERRABLE
+            (_, true) => Some(Deferrable::InitiallyDeferred),
+            (Some(true), false) => Some(Deferrable::InitiallyImmediate),
+            _ => None,
+        })
+    }
+
     pub fn parse_optional_table_constraint(
         &mut self,
     ) -> Result<Option<TableConstraint>, ParserError> {
@@ -1763,6 +1838,7 @@ impl<'a> Parser<'a> {
                         break;
                     }
                 }
+                let deferrable = self.parse_deferrable()?;
                 Ok(Some(TableConstraint::ForeignKey(ForeignKey {
                     name,
                     columns,
@@ -1770,6 +1846,7 @@ impl<'a> Parser<'a> {
                     referred_columns,
                     on_delete,
                     on_update,
+                    deferrable,
                 })))
             }
             Token::Word(w) if w.keyword == Keyword::CHECK => {
@@ -1874,6 +1951,9 @@ impl<'a> Parser<'a> {
                     cascade,
                     restrict,
                 }
+            } else if self.parse_keyword(Keyword::INDEX) {
+                let name = self.parse_identifier()?;
+                AlterTableOperation::DropIndex { name }
             } else {
                 let _ = self.parse_keyword(Keyword::COLUMN);
                 let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
@@ -1885,6 +1965,19 @@ impl<'a> Parser<'a> {
                     cascade,
                 }
             }
+        } else if self.parse_keyword(Keyword::ALTER) {
+            let _ = self.parse_keyword(Keyword::COLUMN);
+            let column_name = self.parse_identifier()?;
+            let op = self.parse_alter_column_operation()?;
+            AlterTableOperation::AlterColumn { column_name, op }
+        } else if self.parse_keyword(Keyword::MODIFY) {
+            let _ = self.parse_keyword(Keyword::COLUMN);
+            let column_def = self.parse_column_def()?;
+            AlterTableOperation::ModifyColumn { column_def }
+        } else if self.parse_keyword(Keyword::COMMENT) {
+            let _ = self.consume_token(&Token::Eq);
+            let comment = self.parse_literal_string()?;
+            AlterTableOperation::SetComment { comment }
         } else if self.parse_keyword(Keyword::PARTITION) {
             self.expect_token(&Token::LParen)?;
             let before = self.parse_comma_separated(Parser::parse_expr)?;
@@ -1900,7 +1993,7 @@ impl<'a> Parser<'a> {
             }
         } else {
             return self.expected(
-                "ADD, RENAME, PARTITION or DROP after ALTER TABLE",
+                "ADD, RENAME, PARTITION, ALTER, MODIFY, COMMENT or DROP after ALTER TABLE",
                 self.peek_token(),
             );
         };
@@ -1910,6 +2003,91 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// PostgreSQL-specific `COMMENT ON { TABLE | COLUMN } <name> IS { '<comment>' | NULL }`
+    pub fn parse_comment(&mut self) -> Result<Comment, ParserError> {
+        self.expect_keyword(Keyword::ON)?;
+        let object_type = match self.parse_one_of_keywords(&[Keyword::TABLE, Keyword::COLUMN]) {
+            Some(Keyword::TABLE) => CommentObject::Table,
+            Some(Keyword::COLUMN) => CommentObject::Column,
+            _ => return self.expected("TABLE or COLUMN after COMMENT ON", self.peek_token()),
+        };
+        let name = self.parse_object_name()?;
+        self.expect_keyword(Keyword::IS)?;
+        let comment = if self.parse_keyword(Keyword::NULL) {
+            None
+        } else {
+            Some(self.parse_literal_string()?)
+        };
+        Ok(Comment {
+            object_type,
+            name,
+            comment,
+        })
+    }
+
+    /// PostgreSQL-specific `ALTER TYPE`
+    pub fn parse_alter_type(&mut self) -> Result<AlterType, ParserError> {
+        let name = self.parse_object_name()?;
+        let operation = if self.parse_keywords(&[Keyword::ADD, Keyword::VALUE]) {
+            let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+            let value = self.parse_literal_string()?;
+            let position = if self.parse_keyword(Keyword::BEFORE) {
+                Some(EnumValuePosition::Before(self.parse_literal_string()?))
+            } else if self.parse_keyword(Keyword::AFTER) {
+                Some(EnumValuePosition::After(self.parse_literal_string()?))
+            } else {
+                None
+            };
+            AlterTypeOperation::AddValue {
+                if_not_exists,
+                value,
+                position,
+            }
+        } else if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            let new_name = self.parse_identifier()?;
+            AlterTypeOperation::RenameTo { new_name }
+        } else if self.parse_keywords(&[Keyword::RENAME, Keyword::VALUE]) {
+            let from = self.parse_literal_string()?;
+            self.expect_keyword(Keyword::TO)?;
+            let to = self.parse_literal_string()?;
+            AlterTypeOperation::RenameValue { from, to }
+        } else {
+            return self.expected(
+                "ADD VALUE, RENAME TO or RENAME VALUE after ALTER TYPE",
+                self.peek_token(),
+            );
+        };
+        Ok(AlterType { name, operation })
+    }
+
+    pub fn parse_alter_column_operation(&mut self) -> Result<AlterColumnOperation, ParserError> {
+        if self.parse_keywords(&[Keyword::SET, Keyword::NOT, Keyword::NULL]) {
+            Ok(AlterColumnOperation::SetNotNull)
+        } else if self.parse_keywords(&[Keyword::DROP, Keyword::NOT, Keyword::NULL]) {
+            Ok(AlterColumnOperation::DropNotNull)
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::DEFAULT]) {
+            let value = self.parse_expr()?;
+            Ok(AlterColumnOperation::SetDefault { value })
+        } else if self.parse_keywords(&[Keyword::DROP, Keyword::DEFAULT]) {
+            Ok(AlterColumnOperation::DropDefault)
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::DATA, Keyword::TYPE])
+            || self.parse_keyword(Keyword::TYPE)
+        {
+            let data_type = self.parse_data_type()?;
+            let using = if self.parse_keyword(Keyword::USING) {
+                Some(self.parse_expr()?)
+            } else {
+                None
+            };
+            Ok(AlterColumnOperation::SetDataType { data_type, using })
+        } else {
+            self.expected(
+                "SET/DROP NOT NULL, SET DEFAULT, SET DATA TYPE after ALTER COLUMN",
+                self.peek_token(),
+            )
+        }
+    }
+
     /// Parse a copy statement
     pub fn parse_copy(&mut self) -> Result<Copy, ParserError> {
         let table_name = self.parse_object_name()?;
@@ -2020,6 +2198,48 @@ impl<'a> Parser<'a> {
 
     /// Parse a SQL datatype (in the context of a CREATE TABLE statement for example)
     pub fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
+        let mut data_type = self.parse_element_type()?;
+        // Note: this is postgresql-specific
+        loop {
+            if self.consume_token(&Token::LBracket) {
+                self.parse_array_size()?;
+            } else if self.parse_keyword(Keyword::ARRAY) {
+                if self.consume_token(&Token::LBracket) {
+                    self.parse_array_size()?;
+                }
+            } else {
+                break;
+            }
+            data_type = DataType::Array(Box::new(data_type));
+        }
+        Ok(data_type)
+    }
+
+    /// Parse the optional `WITH TIME ZONE` or `WITHOUT TIME ZONE` of a temporal type,
+    /// returning whether the type has a time zone.
+    fn parse_time_zone(&mut self) -> Result<bool, ParserError> {
+        let with_time_zone = if self.parse_keyword(Keyword::WITH) {
+            true
+        } else if self.parse_keyword(Keyword::WITHOUT) {
+            false
+        } else {
+            return Ok(false);
+        };
+        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+        Ok(with_time_zone)
+    }
+
+    /// Parse the optional size of an array type followed by `]`.
+    /// The size is discarded, as it is not enforced by postgres.
+    fn parse_array_size(&mut self) -> Result<(), ParserError> {
+        if let Token::Number(..) = self.peek_token() {
+            self.parse_literal_uint()?;
+        }
+        self.expect_token(&Token::RBracket)
+    }
+
+    /// Parse a data type that is not an array
+    fn parse_element_type(&mut self) -> Result<DataType, ParserError> {
         match self.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::BOOLEAN => Ok(DataType::Boolean),
@@ -2030,12 +2250,38 @@ impl<'a> Parser<'a> {
                     Ok(DataType::Double)
                 }
 
-                Keyword::TINYINT => Ok(DataType::TinyInt(self.parse_optional_precision()?)),
-                Keyword::SMALLINT => Ok(DataType::SmallInt(self.parse_optional_precision()?)),
+                Keyword::TINYINT => {
+                    let precision = self.parse_optional_precision()?;
+                    if self.parse_keyword(Keyword::UNSIGNED) {
+                        Ok(DataType::UnsignedTinyInt(precision))
+                    } else {
+                        Ok(DataType::TinyInt(precision))
+                    }
+                }
+                Keyword::SMALLINT => {
+                    let precision = self.parse_optional_precision()?;
+                    if self.parse_keyword(Keyword::UNSIGNED) {
+                        Ok(DataType::UnsignedSmallInt(precision))
+                    } else {
+                        Ok(DataType::SmallInt(precision))
+                    }
+                }
                 Keyword::INT | Keyword::INTEGER => {
-                    Ok(DataType::Int(self.parse_optional_precision()?))
+                    let precision = self.parse_optional_precision()?;
+                    if self.parse_keyword(Keyword::UNSIGNED) {
+                        Ok(DataType::UnsignedInt(precision))
+                    } else {
+                        Ok(DataType::Int(precision))
+                    }
+                }
+                Keyword::BIGINT => {
+                    let precision = self.parse_optional_precision()?;
+                    if self.parse_keyword(Keyword::UNSIGNED) {
+                        Ok(DataType::UnsignedBigInt(precision))
+                    } else {
+                        Ok(DataType::BigInt(precision))
+                    }
                 }
-                Keyword::BIGINT => Ok(DataType::BigInt(self.parse_optional_precision()?)),
                 Keyword::VARCHAR => Ok(DataType::Varchar(self.parse_optional_precision()?)),
                 Keyword::VARBINARY => Ok(DataType::Varbinary(self.parse_optional_precision()?)),
                 Keyword::BLOB => Ok(DataType::Blob(self.parse_optional_precision()?)),
@@ -2048,36 +2294,20 @@ impl<'a> Parser<'a> {
                 }
                 Keyword::UUID => Ok(DataType::Uuid),
                 Keyword::DATE => Ok(DataType::Date),
-                Keyword::TIMESTAMP => {
-                    // TBD: we throw away "with/without timezone" information
-                    if self.parse_keyword(Keyword::WITH) || self.parse_keyword(Keyword::WITHOUT) {
-                        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
-                    }
-                    Ok(DataType::Timestamp)
-                }
-                Keyword::TIME => {
-                    // TBD: we throw away "with/without timezone" information
-                    if self.parse_keyword(Keyword::WITH) || self.parse_keyword(Keyword::WITHOUT) {
-                        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
-                    }
-                    Ok(DataType::Time)
-                }
+                Keyword::TIMESTAMP => Ok(DataType::Timestamp(self.parse_time_zone()?)),
+                Keyword::TIME => Ok(DataType::Time(self.parse_time_zone()?)),
+                // Note: these are postgresql-specific
+                Keyword::TIMESTAMPTZ => Ok(DataType::Timestamp(true)),
+                Keyword::TIMETZ => Ok(DataType::Time(true)),
                 // Interval types can be followed by a complicated interval
                 // qualifier that we don't currently support. See
                 // parse_interval_literal for a taste.
                 Keyword::INTERVAL => Ok(DataType::Interval),
                 Keyword::REGCLASS => Ok(DataType::Regclass),
                 Keyword::STRING => Ok(DataType::String),
-                Keyword::TEXT => {
-                    if self.consume_token(&Token::LBracket) {
-                        // Note: this is postgresql-specific
-                        self.expect_token(&Token::RBracket)?;
-                        Ok(DataType::Array(Box::new(DataType::Text)))
-                    } else {
-                        Ok(DataType::Text)
-                    }
-                }
+                Keyword::TEXT => Ok(DataType::Text),
                 Keyword::JSON => Ok(DataType::Json),
+                Keyword::ENUM => Ok(DataType::Enum(self.parse_enum_labels()?)),
                 Keyword::SERIAL => Ok(DataType::Serial),
                 Keyword::BIGSERIAL => Ok(DataType::BigSerial),
                 Keyword::BYTEA => Ok(DataType::Bytea),
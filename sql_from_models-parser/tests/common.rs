@@ -1285,7 +1285,8 @@ fn parse_create_table() {
                         foreign_table: ObjectName(vec!["othertable3".into()]),
                         referred_columns: vec!["lat".into()],
                         on_delete: Some(ReferentialAction::Restrict),
-                        on_update: None
+                        on_update: None,
+                        deferrable: None
                     }),
                     TableConstraint::ForeignKey(ForeignKey {
                         name: Some("fkey2".into()),
@@ -1293,7 +1294,8 @@ fn parse_create_table() {
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["lat".into()],
                         on_delete: Some(ReferentialAction::NoAction),
-                        on_update: Some(ReferentialAction::Restrict)
+                        on_update: Some(ReferentialAction::Restrict),
+                        deferrable: None
                     }),
                     TableConstraint::ForeignKey(ForeignKey {
                         name: None,
@@ -1301,7 +1303,8 @@ fn parse_create_table() {
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["lat".into()],
                         on_delete: Some(ReferentialAction::Cascade),
-                        on_update: Some(ReferentialAction::SetDefault)
+                        on_update: Some(ReferentialAction::SetDefault),
+                        deferrable: None
                     }),
                     TableConstraint::ForeignKey(ForeignKey {
                         name: None,
@@ -1309,7 +1312,8 @@ fn parse_create_table() {
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["longitude".into()],
                         on_delete: None,
-                        on_update: Some(ReferentialAction::SetNull)
+                        on_update: Some(ReferentialAction::SetNull),
+                        deferrable: None
                     }),
                 ]
             );
@@ -1779,6 +1783,55 @@ fn parse_alter_table_drop_constraint() {
     assert_eq!(plain, &format!("{}", verified_stmt(plain)));
 }
 
+#[test]
+fn parse_alter_table_drop_index() {
+    let sql = "ALTER TABLE tab DROP INDEX idx_name";
+    match verified_stmt(sql) {
+        Statement::AlterTable(AlterTable {
+            name,
+            operation: AlterTableOperation::DropIndex { name: index_name },
+        }) => {
+            assert_eq!("tab", name.to_string());
+            assert_eq!("idx_name", index_name.to_string());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_alter_column() {
+    let alter_stmt = "ALTER TABLE tab";
+    match verified_stmt(&format!("{} ALTER COLUMN is_active SET NOT NULL", alter_stmt)) {
+        Statement::AlterTable(AlterTable {
+            name,
+            operation: AlterTableOperation::AlterColumn { column_name, op },
+        }) => {
+            assert_eq!("tab", name.to_string());
+            assert_eq!("is_active", column_name.to_string());
+            assert_eq!(op, AlterColumnOperation::SetNotNull);
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt(&format!("{} ALTER COLUMN is_active DROP NOT NULL", alter_stmt));
+    verified_stmt(&format!("{} ALTER COLUMN is_active SET DEFAULT false", alter_stmt));
+    verified_stmt(&format!("{} ALTER COLUMN is_active DROP DEFAULT", alter_stmt));
+    verified_stmt(&format!("{} ALTER COLUMN is_active SET DATA TYPE TEXT", alter_stmt));
+    one_statement_parses_to(
+        &format!("{} ALTER is_active TYPE TEXT", alter_stmt),
+        &format!("{} ALTER COLUMN is_active SET DATA TYPE TEXT", alter_stmt),
+    );
+
+    let res = parse_sql_statements(&format!("{} ALTER COLUMN is_active SET NULL", alter_stmt));
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected SET/DROP NOT NULL, SET DEFAULT, SET DATA TYPE after ALTER COLUMN, found: SET"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_bad_constraint() {
     let res = parse_sql_statements("ALTER TABLE tab ADD");
@@ -1960,7 +2013,7 @@ fn parse_literal_time() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::TypedString(TypedString {
-            data_type: DataType::Time,
+            data_type: DataType::Time(false),
             value: "01:23:34".into()
         }),
         expr_from_projection(only(&select.projection)),
@@ -1973,7 +2026,7 @@ fn parse_literal_timestamp() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::TypedString(TypedString {
-            data_type: DataType::Timestamp,
+            data_type: DataType::Timestamp(false),
             value: "1999-01-01 01:23:34".into()
         }),
         expr_from_projection(only(&select.projection)),
@@ -222,6 +222,44 @@ fn parse_create_table_with_minimum_display_width() {
     }
 }
 
+#[test]
+fn parse_alter_table_modify_column() {
+    let sql = "ALTER TABLE tab MODIFY COLUMN price BIGINT NOT NULL";
+    match mysql().verified_stmt(sql) {
+        Statement::AlterTable(AlterTable {
+            name,
+            operation: AlterTableOperation::ModifyColumn { column_def },
+        }) => {
+            assert_eq!("tab", name.to_string());
+            assert_eq!("price", column_def.name.to_string());
+            assert_eq!(DataType::BigInt(None), column_def.data_type);
+        }
+        _ => unreachable!(),
+    }
+    mysql().one_statement_parses_to(
+        "ALTER TABLE tab MODIFY price BIGINT NOT NULL",
+        "ALTER TABLE tab MODIFY COLUMN price BIGINT NOT NULL",
+    );
+}
+
+#[test]
+fn parse_create_table_with_enum() {
+    let sql = "CREATE TABLE person (mood ENUM('sad', 'ok') NOT NULL)";
+    match mysql().one_statement_parses_to(sql, sql) {
+        Statement::CreateTable(table) => {
+            assert_eq!(
+                DataType::Enum(vec!["sad".into(), "ok".into()]),
+                table.columns[0].data_type
+            );
+        }
+        _ => unreachable!(),
+    }
+    mysql().one_statement_parses_to(
+        "CREATE TABLE person (mood enum('sad','ok'))",
+        "CREATE TABLE person (mood ENUM('sad', 'ok'))",
+    );
+}
+
 fn mysql() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MySqlDialect {})],
@@ -233,3 +271,53 @@ fn mysql_and_generic() -> TestedDialects {
         dialects: vec![Box::new(MySqlDialect {}), Box::new(GenericDialect {})],
     }
 }
+
+#[test]
+fn parse_create_table_unsigned() {
+    let sql = "CREATE TABLE foo (bar_tinyint TINYINT UNSIGNED, bar_smallint SMALLINT(5) UNSIGNED, bar_int INT UNSIGNED, bar_bigint BIGINT(20) UNSIGNED)";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(table) => {
+            let types: Vec<_> = table.columns.into_iter().map(|col| col.data_type).collect();
+            assert_eq!(
+                types,
+                vec![
+                    DataType::UnsignedTinyInt(None),
+                    DataType::UnsignedSmallInt(Some(5)),
+                    DataType::UnsignedInt(None),
+                    DataType::UnsignedBigInt(Some(20)),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    mysql().one_statement_parses_to(
+        "CREATE TABLE foo (bar INTEGER UNSIGNED NOT NULL)",
+        "CREATE TABLE foo (bar INT UNSIGNED NOT NULL)",
+    );
+}
+
+#[test]
+fn parse_create_table_with_generated_columns() {
+    mysql().one_statement_parses_to(
+        "CREATE TABLE t (a INT, b INT AS (a + 1), c INT AS (a + 2) STORED)",
+        "CREATE TABLE t (a INTEGER, b INTEGER GENERATED ALWAYS AS (a + 1) VIRTUAL, c INTEGER GENERATED ALWAYS AS (a + 2) STORED)",
+    );
+}
+
+#[test]
+fn parse_create_table_with_comments() {
+    match mysql().one_statement_parses_to(
+        "CREATE TABLE t (a INT NOT NULL COMMENT 'it''s a') COMMENT 'table t'",
+        "CREATE TABLE t (a INTEGER NOT NULL COMMENT 'it''s a') COMMENT = 'table t'",
+    ) {
+        Statement::CreateTable(table) => {
+            assert_eq!(table.comment.as_deref(), Some("table t"));
+            assert_eq!(
+                table.columns[0].options[1].option,
+                ColumnOption::Comment("it's a".into())
+            );
+        }
+        _ => unreachable!(),
+    }
+    mysql().verified_stmt("ALTER TABLE t COMMENT = 'table t'");
+}
@@ -136,7 +136,7 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "last_update".into(),
-                        data_type: DataType::Timestamp,
+                        data_type: DataType::Timestamp(false),
                         collation: None,
                         options: vec![
                             ColumnOptionDef {
@@ -227,6 +227,131 @@ fn parse_create_table_with_inherit() {
     pg().verified_stmt(sql);
 }
 
+#[test]
+fn parse_create_table_with_arrays() {
+    let sql = "CREATE TABLE t (\
+               scores INTEGER[], \
+               grid DOUBLE PRECISION[][], \
+               names VARCHAR(45)[], \
+               moods mood[]\
+               )";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable(table) => {
+            let types: Vec<_> = table.columns.into_iter().map(|col| col.data_type).collect();
+            let array = |ty| DataType::Array(Box::new(ty));
+            assert_eq!(
+                types,
+                vec![
+                    array(DataType::Int(None)),
+                    array(array(DataType::Double)),
+                    array(DataType::Varchar(Some(45))),
+                    array(DataType::custom("mood")),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg().one_statement_parses_to(
+        "CREATE TABLE t (scores int[3], names text ARRAY, tags text ARRAY[4])",
+        "CREATE TABLE t (scores INTEGER[], names TEXT[], tags TEXT[])",
+    );
+}
+
+#[test]
+fn parse_create_table_with_time_zones() {
+    let sql = "CREATE TABLE t (\
+               a TIMESTAMP WITH TIME ZONE, \
+               b TIMESTAMP WITHOUT TIME ZONE, \
+               c TIMESTAMPTZ, \
+               d TIME WITH TIME ZONE, \
+               e TIMETZ, \
+               f TIME\
+               )";
+    match pg().one_statement_parses_to(
+        sql,
+        "CREATE TABLE t (a TIMESTAMPTZ, b TIMESTAMP, c TIMESTAMPTZ, d TIMETZ, e TIMETZ, f TIME)",
+    ) {
+        Statement::CreateTable(table) => {
+            let types: Vec<_> = table.columns.into_iter().map(|col| col.data_type).collect();
+            assert_eq!(
+                types,
+                vec![
+                    DataType::Timestamp(true),
+                    DataType::Timestamp(false),
+                    DataType::Timestamp(true),
+                    DataType::Time(true),
+                    DataType::Time(true),
+                    DataType::Time(false),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_table_with_deferrable_foreign_keys() {
+    let sql = "CREATE TABLE t (\
+               a INTEGER, \
+               b INTEGER, \
+               CONSTRAINT fa FOREIGN KEY (a) REFERENCES o(id) ON DELETE SET DEFAULT DEFERRABLE, \
+               CONSTRAINT fb FOREIGN KEY (b) REFERENCES o(id) DEFERRABLE INITIALLY DEFERRED\
+               )";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable(table) => {
+            let deferrable: Vec<_> = table
+                .constraints
+                .into_iter()
+                .map(|constr| match constr {
+                    TableConstraint::ForeignKey(fk) => fk.deferrable,
+                    _ => unreachable!(),
+                })
+                .collect();
+            assert_eq!(
+                deferrable,
+                vec![
+                    Some(Deferrable::InitiallyImmediate),
+                    Some(Deferrable::InitiallyDeferred)
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg().one_statement_parses_to(
+        "CREATE TABLE t (a INTEGER, FOREIGN KEY (a) REFERENCES o(id) NOT DEFERRABLE INITIALLY IMMEDIATE)",
+        "CREATE TABLE t (a INTEGER, FOREIGN KEY (a) REFERENCES o(id))",
+    );
+    pg().one_statement_parses_to(
+        "CREATE TABLE t (a INTEGER, FOREIGN KEY (a) REFERENCES o(id) INITIALLY DEFERRED)",
+        "CREATE TABLE t (a INTEGER, FOREIGN KEY (a) REFERENCES o(id) DEFERRABLE INITIALLY DEFERRED)",
+    );
+    assert!(pg()
+        .parse_sql_statements(
+            "CREATE TABLE t (a INTEGER, FOREIGN KEY (a) REFERENCES o(id) NOT DEFERRABLE INITIALLY DEFERRED)"
+        )
+        .is_err());
+}
+
+#[test]
+fn parse_create_table_with_generated_columns() {
+    let sql = "CREATE TABLE t (\
+               a INTEGER, \
+               b INTEGER GENERATED ALWAYS AS (a * 2) STORED NOT NULL\
+               )";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable(table) => {
+            assert_eq!(
+                table.columns[1].options[0].option,
+                ColumnOption::Generated {
+                    expr: pg().verified_expr("a * 2"),
+                    stored: true,
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_create_table_empty() {
     // Zero-column tables are weird, but supported by at least PostgreSQL.
@@ -662,6 +787,84 @@ fn parse_pg_regex_match_ops() {
     }
 }
 
+#[test]
+fn parse_create_type_as_enum() {
+    let sql = "CREATE TYPE mood AS ENUM ('sad', 'ok', 'it''s fine')";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateType(CreateType { name, labels }) => {
+            assert_eq!("mood", name.to_string());
+            assert_eq!(labels, ["sad", "ok", "it's fine"]);
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("CREATE TYPE mood AS ENUM ()");
+}
+
+#[test]
+fn parse_alter_type() {
+    match pg().verified_stmt("ALTER TYPE mood ADD VALUE IF NOT EXISTS 'happy' AFTER 'ok'") {
+        Statement::AlterType(AlterType { name, operation }) => {
+            assert_eq!("mood", name.to_string());
+            assert_eq!(
+                operation,
+                AlterTypeOperation::AddValue {
+                    if_not_exists: true,
+                    value: "happy".into(),
+                    position: Some(EnumValuePosition::After("ok".into())),
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("ALTER TYPE mood ADD VALUE 'angry' BEFORE 'sad'");
+    pg().verified_stmt("ALTER TYPE mood ADD VALUE 'happy'");
+    pg().verified_stmt("ALTER TYPE mood RENAME TO feeling");
+    pg().verified_stmt("ALTER TYPE mood RENAME VALUE 'ok' TO 'fine'");
+}
+
+#[test]
+fn parse_comment_on() {
+    let sql = "COMMENT ON COLUMN invoice.total IS 'Total in cents, it''s rounded'";
+    match pg().verified_stmt(sql) {
+        Statement::Comment(Comment {
+            object_type,
+            name,
+            comment,
+        }) => {
+            assert_eq!(object_type, CommentObject::Column);
+            assert_eq!("invoice.total", name.to_string());
+            assert_eq!(comment.as_deref(), Some("Total in cents, it's rounded"));
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("COMMENT ON TABLE invoice IS 'Issued invoices'");
+    pg().verified_stmt("COMMENT ON TABLE invoice IS NULL");
+}
+
+#[test]
+fn parse_drop_type() {
+    match pg().verified_stmt("DROP TYPE IF EXISTS mood CASCADE") {
+        Statement::Drop(Drop {
+            object_type,
+            if_exists,
+            cascade,
+            ..
+        }) => {
+            assert_eq!(object_type, ObjectType::Type);
+            assert!(if_exists);
+            assert!(cascade);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_column_type_using() {
+    pg().verified_stmt(
+        "ALTER TABLE person ALTER COLUMN mood SET DATA TYPE mood USING CAST(CAST(mood AS TEXT) AS mood)",
+    );
+}
+
 fn pg() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(PostgreSqlDialect {})],
@@ -2,11 +2,13 @@ mod migration_generation;
 // mod getters;
 mod model;
 mod prelude;
+mod sql_enum;
 use migration_generation::*;
 use model::*;
 use prelude::*;
+use sql_enum::SqlEnum;
 
-#[proc_macro_derive(Model, attributes(model, primary_key, foreign_key, unique, default))]
+#[proc_macro_derive(Model, attributes(model, primary_key, foreign_key, unique, index, check, default, generated, nullable, not_null))]
 pub fn model(input: TokenStream) -> TokenStream {
     let derive = parse_macro_input!(input as Model);
 
@@ -17,3 +19,9 @@ pub fn model(input: TokenStream) -> TokenStream {
     };
     template.into()
 }
+
+#[proc_macro_derive(SqlEnum, attributes(model))]
+pub fn sql_enum(input: TokenStream) -> TokenStream {
+    let derive = parse_macro_input!(input as SqlEnum);
+    quote!(#derive).into()
+}
@@ -1,30 +1,15 @@
 use crate::prelude::*;
-// SQLX_MODELS_GENERATE_MIGRATION=true
-// MODELS_GENERATE_MIGRATIONS
 
+/// Registers the model so the CLI can generate its migrations.
+/// The registry is collected at link time by the harness binary the CLI builds.
 pub fn generate_migration(name: &Ident) -> TokenStream2 {
-    if let Ok(value) = std::env::var("MODELS_GENERATE_MIGRATIONS") {
-        if value.to_lowercase() == "true" {
-            generate_migration_unchecked(name)
-        } else {
-            quote!()
-        }
-    } else {
-        quote!()
-    }
-}
-
-fn generate_migration_unchecked(name: &Ident) -> TokenStream2 {
-    let test_name = Ident::new(
-        &format!("__models_generate_migration_{}", name),
-        proc_macro2::Span::call_site(),
-    );
+    let target = format_ident!("__models_target_{}", name);
+    let model = name.to_string();
     quote! {
-        #[test]
-        fn #test_name() {
-            ::sql_from_models::private::SCHEDULER.register(
-                <#name as ::sql_from_models::private::Model>::target()
-            );
-        }
+        #[::sql_from_models::private::linkme::distributed_slice(::sql_from_models::private::MODELS)]
+        #[linkme(crate = ::sql_from_models::private::linkme)]
+        #[allow(non_upper_case_globals)]
+        static #target: (&'static str, fn() -> ::sql_from_models::private::Table) =
+            (#model, <#name as ::sql_from_models::private::Model>::target);
     }
 }
@@ -0,0 +1,183 @@
+use crate::prelude::*;
+
+/// Options declared through `#[model(...)]` attributes.
+#[derive(Default)]
+pub struct ModelAttrs {
+    pub renamed_from: Option<LitStr>,
+    pub table: Option<LitStr>,
+    pub column: Option<LitStr>,
+    pub rename_all: Option<LitStr>,
+    /// set by `#[model(skip)]` on fields that are not columns.
+    pub skip: Option<Ident>,
+}
+
+/// Naming policy used to derive table and column names from Rust identifiers.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lowercase,
+    SnakeCase,
+}
+
+/// joins the lines of the `///` doc comments, which become the comment of the table or column.
+pub fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<_> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(line),
+                        ..
+                    }),
+                ..
+            }) => Some(line.value().trim().to_string()),
+            _ => None,
+        })
+        .collect();
+    let comment = lines.join("\n").trim().to_string();
+    Some(comment).filter(|comment| !comment.is_empty())
+}
+
+impl ModelAttrs {
+    /// parses the attributes of the structure.
+    pub fn from_struct(attrs: &[Attribute]) -> Result<Self> {
+        let out = Self::from_attrs(attrs)?;
+        if let Some(column) = &out.column {
+            return Err(Error::new(
+                column.span(),
+                "`column` can only be used on fields. help: use `table` instead.",
+            ));
+        }
+        if let Some(skip) = &out.skip {
+            return Err(Error::new(
+                skip.span(),
+                "`skip` can only be used on fields.",
+            ));
+        }
+        Ok(out)
+    }
+
+    /// parses the attributes of a field.
+    pub fn from_field(attrs: &[Attribute]) -> Result<Self> {
+        let out = Self::from_attrs(attrs)?;
+        if let Some(table) = &out.table {
+            return Err(Error::new(
+                table.span(),
+                "`table` can only be used on structures. help: use `column` instead.",
+            ));
+        }
+        if let Some(rename_all) = &out.rename_all {
+            return Err(Error::new(
+                rename_all.span(),
+                "`rename_all` can only be used on structures.",
+            ));
+        }
+        if let (Some(skip), true) = (
+            &out.skip,
+            out.column.is_some() || out.renamed_from.is_some(),
+        ) {
+            return Err(Error::new(
+                skip.span(),
+                "`skip` cannot be used along with `column` or `renamed_from`.",
+            ));
+        }
+        Ok(out)
+    }
+
+    pub fn rename_rule(&self) -> Result<RenameRule> {
+        match &self.rename_all {
+            None => Ok(RenameRule::Lowercase),
+            Some(rule) => match &*rule.value() {
+                "lowercase" => Ok(RenameRule::Lowercase),
+                "snake_case" => Ok(RenameRule::SnakeCase),
+                _ => Err(Error::new(
+                    rule.span(),
+                    format!(
+                        "invalid naming policy. Found {:?}, expected one of: {:?}",
+                        rule.value(),
+                        ["lowercase", "snake_case"],
+                    ),
+                )),
+            },
+        }
+    }
+
+    fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut out = ModelAttrs::default();
+        for attr in attrs {
+            if attr.path().is_ident("model") {
+                attr.parse_args_with(|input: parse::ParseStream| out.parse_options(input))?;
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_options(&mut self, input: parse::ParseStream) -> Result<()> {
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            if ident == "skip" {
+                if self.skip.is_some() {
+                    return Err(Error::new(ident.span(), "Expected a single `skip`."));
+                }
+                self.skip = Some(ident);
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+                continue;
+            }
+            let option = if ident == "renamed_from" {
+                &mut self.renamed_from
+            } else if ident == "table" {
+                &mut self.table
+            } else if ident == "column" {
+                &mut self.column
+            } else if ident == "rename_all" {
+                &mut self.rename_all
+            } else {
+                return Err(Error::new(
+                    ident.span(),
+                    "Expected `renamed_from`, `table`, `column`, `rename_all` or `skip`.",
+                ));
+            };
+            input.parse::<Token![=]>()?;
+            if option.is_some() {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("Expected a single `{}`.", ident),
+                ));
+            }
+            *option = Some(input.parse()?);
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RenameRule {
+    pub fn apply(&self, name: &str) -> String {
+        match self {
+            RenameRule::Lowercase => name.to_lowercase(),
+            RenameRule::SnakeCase => to_snake_case(name),
+        }
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, &ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() && i != 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = matches!(chars.get(i + 1), Some(next) if next.is_lowercase());
+            // `PostLike` -> `post_like` and `HTTPServer` -> `http_server`
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(ch.to_lowercase());
+    }
+    out
+}
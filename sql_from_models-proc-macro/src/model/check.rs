@@ -0,0 +1,130 @@
+use super::Model;
+use crate::prelude::*;
+
+/// A check constraint declared with `#[check("...")]`.
+/// The expression is parsed at compile time so syntax errors are reported by the derive.
+pub struct Check {
+    field: Option<Ident>,
+    /// index of the check among the ones declared on the same field or structure.
+    position: usize,
+    expr: LitStr,
+    /// unquoted words of the expression, which may name columns.
+    words: Vec<String>,
+    name: Option<LitStr>,
+}
+
+impl Check {
+    /// parses the `#[check]` attributes of the structure.
+    pub fn from_struct(attrs: &[Attribute]) -> Result<Vec<Self>> {
+        Self::from_attrs(attrs, None)
+    }
+
+    /// parses the `#[check]` attributes of a field.
+    pub fn from_field(field: &Field) -> Result<Vec<Self>> {
+        Self::from_attrs(&field.attrs, field.ident.as_ref())
+    }
+
+    fn from_attrs(attrs: &[Attribute], field: Option<&Ident>) -> Result<Vec<Self>> {
+        let mut out = vec![];
+        for attr in attrs {
+            if attr.path().is_ident("check") {
+                let mut check: Check = attr.parse_args()?;
+                check.field = field.cloned();
+                check.position = out.len();
+                out.push(check);
+            }
+        }
+        Ok(out)
+    }
+
+    /// returns true if the expression contains `word`, ignoring case.
+    pub fn mentions(&self, word: &str) -> bool {
+        self.words.iter().any(|w| w.eq_ignore_ascii_case(word))
+    }
+
+    pub fn expr(&self) -> &LitStr {
+        &self.expr
+    }
+
+    pub fn tokens(&self, model: &Model) -> TokenStream2 {
+        let position = self.position;
+        let expr = self.expr.value();
+        let name = match (&self.name, &self.field) {
+            (Some(name), _) => name.value().to_lowercase(),
+            (None, field) => {
+                let mut name = format!("{}_check", model.table_name());
+                if let Some(field) = field {
+                    name += "_";
+                    name += &model.column_name(field);
+                }
+                if position != 0 || field.is_none() {
+                    name += &format!("_{}", position);
+                }
+                name
+            }
+        };
+        quote! {
+            __models_table.constraints.push(
+                ::sql_from_models::private::constraint::check(#name, #expr)
+            );
+        }
+    }
+}
+
+impl Parse for Check {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let expr: LitStr = input.parse()?;
+        let words = validate(&expr)?;
+        let mut name = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let ident: Ident = input.parse()?;
+            if ident != "name" {
+                return Err(Error::new(ident.span(), "Expected `name`."));
+            }
+            input.parse::<Token![=]>()?;
+            if name.is_some() {
+                return Err(Error::new(ident.span(), "Expected a single `name`."));
+            }
+            name = Some(input.parse()?);
+        }
+        Ok(Check {
+            field: None,
+            position: 0,
+            expr,
+            words,
+            name,
+        })
+    }
+}
+
+/// parses the expression and returns its unquoted words.
+fn validate(expr: &LitStr) -> Result<Vec<String>> {
+    use sql_from_models_parser::{dialect::*, parser::Parser, tokenizer::*};
+    let span = expr.span();
+    let sql = expr.value();
+    let tokens = Tokenizer::new(&GenericDialect {}, &sql)
+        .tokenize()
+        .map_err(|err| Error::new(span, format!("Failed to tokenize check expression: {:?}", err)))?;
+    let words = tokens
+        .iter()
+        .filter_map(|token| match token {
+            Token::Word(word) if word.quote_style.is_none() => Some(word.value.clone()),
+            _ => None,
+        })
+        .collect();
+    let mut parser = Parser::new(tokens, &GenericDialect {});
+    parser
+        .parse_expr()
+        .map_err(|err| Error::new(span, format!("Failed to parse check expression: {}", err)))?;
+    if parser.peek_token() != Token::EOF {
+        return Err(Error::new(
+            span,
+            format!("Unexpected {} in check expression.", parser.peek_token()),
+        ));
+    }
+    Ok(words)
+}
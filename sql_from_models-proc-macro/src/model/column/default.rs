@@ -1,71 +1,108 @@
 use crate::prelude::*;
 use proc_macro2::Span;
+use sql_from_models_parser::{ast::Expr, dialect::*, parser::Parser, tokenizer::*};
 
-pub struct DefaultExpr {
-    is_string: bool,
-    expr: String,
+pub enum DefaultExpr {
+    /// SQL emitted as is: a literal, a function call or `sql = "..."`.
+    Sql(String),
+    /// A Rust constant, converted to a SQL literal when the table is generated.
+    Constant(Path),
 }
 
 impl ToTokens for DefaultExpr {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        // If this is a numeric or bool literal, just emit it
-        if !self.is_string {
-            let expr = &self.expr;
-            tokens.extend(quote!(#expr));
-        } else {
-            // It's a string, so produce a valid SQL string literal.
-            // e.g. if `expr` is "" (empty), you want `''`
-            let sql_snippet = format!("'{}'", self.expr);
-            let lit_str = syn::LitStr::new(&sql_snippet, proc_macro2::Span::call_site());
-            tokens.extend(quote!(#lit_str));
+        match self {
+            DefaultExpr::Sql(sql) => {
+                let lit_str = syn::LitStr::new(sql, Span::call_site());
+                tokens.extend(quote!(#lit_str));
+            }
+            DefaultExpr::Constant(path) => tokens.extend(quote! {
+                &::sql_from_models::private::DefaultLiteral::default_literal(&#path)
+            }),
         }
     }
 }
 
 impl Parse for DefaultExpr {
     fn parse(input: parse::ParseStream) -> Result<Self> {
-        use sql_from_models_parser::{dialect::*, parser::Parser, tokenizer::*};
+        // A raw SQL expression such as `sql = "CURRENT_TIMESTAMP"`
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let key: Ident = input.parse()?;
+            if key != "sql" {
+                return Err(Error::new(key.span(), "Expected `sql = \"...\"`"));
+            }
+            input.parse::<Token![=]>()?;
+            let sql: LitStr = input.parse()?;
+            return DefaultExpr::sql(sql.value(), sql.span());
+        }
+
+        // A function call such as `gen_random_uuid()` is a shorthand for `sql = "gen_random_uuid()"`
+        if input.peek(Ident) && input.peek2(token::Paren) {
+            let function: Ident = input.parse()?;
+            let content;
+            parenthesized!(content in input);
+            let args: TokenStream2 = content.parse()?;
+            return DefaultExpr::sql(format!("{}({})", function, args), function.span());
+        }
 
-        let span = Span::call_site();
-        let mut is_string = false;
+        // A path to a Rust constant such as `MAX_RETRIES` or `config::TIMEOUT`
+        if input.peek(Ident) || input.peek(Token![::]) {
+            return Ok(DefaultExpr::Constant(input.parse()?));
+        }
 
         // Parse one Rust literal: bool, int, float, or string
-        let expr = match input.parse::<Lit>() {
-            Ok(Lit::Bool(boolean)) => boolean.value().to_string(),
-            Ok(Lit::Int(int))     => int.to_string(),
-            Ok(Lit::Float(float)) => float.to_string(),
-            Ok(Lit::Str(string))  => {
-                is_string = true;
-                string.value() // e.g. "" or "some text"
-            }
-            Ok(_) | Err(_) => {
+        let lit = input.parse::<Lit>().map_err(|_| {
+            Error::new(
+                input.span(),
+                "Expected string, boolean, or numeric literal, a function call, a constant, or `sql = \"...\"`",
+            )
+        })?;
+        let sql = match &lit {
+            Lit::Bool(boolean) => boolean.value().to_string(),
+            Lit::Int(int) => int.to_string(),
+            Lit::Float(float) => float.to_string(),
+            // Strings are emitted as SQL string literals, e.g. "" becomes ''
+            Lit::Str(string) => format!("'{}'", string.value().replace('\'', "''")),
+            _ => {
                 return Err(Error::new(
-                    input.span(),
-                    "Expected string, boolean, or numeric literal"
-                ));
+                    lit.span(),
+                    "Expected string, boolean, or numeric literal",
+                ))
             }
         };
+        DefaultExpr::sql(sql, lit.span())
+    }
+}
 
-        // If the literal is an *empty string* `""`, convert it to `''` for the SQL parser
-        let expr_for_parser = if is_string && expr.is_empty() {
-            "''"
-        } else {
-            &expr
-        };
-
-        // Pass `expr_for_parser` to your SQL parser
-        let mut lexer = Tokenizer::new(&GenericDialect {}, expr_for_parser);
-        let tokens = lexer.tokenize().map_err(|err| {
-            syn::Error::new(span, format!("Failed to tokenize default expression: {:?}", err))
-        })?;
-
-        Parser::new(tokens, &GenericDialect {})
-            .parse_expr()
-            .map_err(|err| {
-                syn::Error::new(span, format!("Failed to parse default expression: {}", err))
-            })?;
+impl DefaultExpr {
+    /// checks that `sql` is a single expression, so invalid defaults fail at compile time.
+    fn sql(sql: String, span: Span) -> Result<Self> {
+        parse_sql(&sql, span)?;
+        Ok(DefaultExpr::Sql(sql))
+    }
+}
 
-        // Return the original `expr` (which might be ""), along with `is_string`.
-        Ok(DefaultExpr { is_string, expr })
+/// Parses the whole of `sql` as an expression.
+fn parse_sql(sql: &str, span: Span) -> Result<Expr> {
+    let mut lexer = Tokenizer::new(&GenericDialect {}, sql);
+    let tokens = lexer.tokenize().map_err(|err| {
+        Error::new(
+            span,
+            format!("Failed to tokenize default expression: {:?}", err),
+        )
+    })?;
+    let mut parser = Parser::new(tokens, &GenericDialect {});
+    let expr = parser
+        .parse_expr()
+        .map_err(|err| Error::new(span, format!("Failed to parse default expression: {}", err)))?;
+    if parser.peek_token() != Token::EOF {
+        return Err(Error::new(
+            span,
+            format!(
+                "Unexpected `{}` after default expression",
+                parser.peek_token()
+            ),
+        ));
     }
+    Ok(expr)
 }
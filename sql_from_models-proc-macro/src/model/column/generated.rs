@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use sql_from_models_parser::{dialect::*, parser::Parser, tokenizer::*};
+use syn::ext::IdentExt;
+
+/// A generated column declared with `#[generated("expr")]` or `#[generated("expr", stored)]`.
+pub struct Generated {
+    expr: LitStr,
+    stored: bool,
+}
+
+impl Generated {
+    pub fn tokens(&self) -> TokenStream2 {
+        let expr = self.expr.value();
+        let stored = self.stored;
+        quote!(__models_column.generated_as(#expr, #stored);)
+    }
+}
+
+impl Parse for Generated {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let expr: LitStr = input.parse()?;
+        validate(&expr)?;
+        let mut stored = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            // `virtual` is a Rust keyword.
+            let ident = input.call(Ident::parse_any)?;
+            if ident != "stored" && ident != "virtual" {
+                return Err(Error::new(ident.span(), "Expected `stored` or `virtual`."));
+            }
+            if stored.is_some() {
+                return Err(Error::new(
+                    ident.span(),
+                    "Expected a single `stored` or `virtual`.",
+                ));
+            }
+            stored = Some(ident == "stored");
+        }
+        Ok(Generated {
+            expr,
+            stored: stored.unwrap_or_default(),
+        })
+    }
+}
+
+fn validate(expr: &LitStr) -> Result<()> {
+    let span = expr.span();
+    let sql = expr.value();
+    let tokens = Tokenizer::new(&GenericDialect {}, &sql)
+        .tokenize()
+        .map_err(|err| {
+            Error::new(
+                span,
+                format!("Failed to tokenize generated expression: {:?}", err),
+            )
+        })?;
+    let mut parser = Parser::new(tokens, &GenericDialect {});
+    parser.parse_expr().map_err(|err| {
+        Error::new(
+            span,
+            format!("Failed to parse generated expression: {}", err),
+        )
+    })?;
+    if parser.peek_token() != Token::EOF {
+        return Err(Error::new(
+            span,
+            format!(
+                "Unexpected {} in generated expression.",
+                parser.peek_token()
+            ),
+        ));
+    }
+    Ok(())
+}
@@ -1,12 +1,22 @@
+use super::attributes::{doc_comment, ModelAttrs, RenameRule};
 use crate::prelude::*;
+use syn::ext::IdentExt;
 mod default;
+mod generated;
 
 use default::*;
+use generated::Generated;
 
 pub struct Column {
-    name: Ident,
+    field_name: String,
+    name: String,
     ty: Type,
     default: Option<DefaultExpr>,
+    generated: Option<Generated>,
+    renamed_from: Option<String>,
+    comment: Option<String>,
+    /// set by `#[nullable]` or `#[not_null]`, overriding the type of the field.
+    is_nullable: Option<bool>,
 }
 
 impl ToTokens for Column {
@@ -14,36 +24,141 @@ impl ToTokens for Column {
         let col_name = &self.name;
         let ty = &self.ty;
         let default = &self.default;
-        let temp = if let Some(default) = default {
+        let is_nullable = match self.is_nullable {
+            Some(is_nullable) => quote!(#is_nullable),
+            None => quote!(<#ty as ::sql_from_models::types::IntoSQL>::IS_NULLABLE),
+        };
+        let column = if let Some(default) = default {
             quote! {
-                __models_table.columns.push(
-                    ::sql_from_models::private::Column::new_with_default(
-                        stringify!(#col_name),
-                        <#ty as ::sql_from_models::types::IntoSQL>::into_sql(),
-                        <#ty as ::sql_from_models::types::IntoSQL>::IS_NULLABLE,
-                        #default
-                ));
+                ::sql_from_models::private::Column::new_with_default(
+                    #col_name,
+                    <#ty as ::sql_from_models::types::IntoSQL>::into_sql(),
+                    #is_nullable,
+                    #default
+                )
             }
         } else {
             quote! {
-                __models_table.columns.push(
-                    ::sql_from_models::private::Column::new(
-                        stringify!(#col_name),
-                        <#ty as ::sql_from_models::types::IntoSQL>::into_sql(),
-                        <#ty as ::sql_from_models::types::IntoSQL>::IS_NULLABLE,
-                ));
+                ::sql_from_models::private::Column::new(
+                    #col_name,
+                    <#ty as ::sql_from_models::types::IntoSQL>::into_sql(),
+                    #is_nullable,
+                )
             }
         };
+        let renamed_from = self.renamed_from.iter();
+        let comment = self.comment.iter();
+        let generated = self.generated.iter().map(Generated::tokens);
+        let temp = quote! {
+            let mut __models_column = #column;
+            #(__models_column.renamed_from = Some(#renamed_from.into());)*
+            #(__models_column.comment = Some(#comment.into());)*
+            #(#generated)*
+            __models_table.columns.push(__models_column);
+        };
         tokens.extend(temp);
     }
 }
 
 impl Column {
-    pub fn new(field: &Field) -> Result<Self> {
+    pub fn new(field: &Field, rename_all: RenameRule) -> Result<Self> {
         let ty = field.ty.clone();
         let default = Self::get_default(field.attrs.clone())?;
-        let name = field.ident.clone().unwrap();
-        Ok(Self { ty, default, name })
+        let generated = Self::get_generated(&field.attrs)?;
+        if let (Some(_), Some(_)) = (&default, &generated) {
+            return Err(Error::new_spanned(
+                field,
+                "A generated column cannot have a default.",
+            ));
+        }
+        let field_name = field.ident.as_ref().unwrap().unraw().to_string();
+        let attrs = ModelAttrs::from_field(&field.attrs)?;
+        let name = match &attrs.column {
+            Some(column) => column.value().to_lowercase(),
+            None => rename_all.apply(&field_name),
+        };
+        let renamed_from = attrs.renamed_from.map(|name| name.value().to_lowercase());
+        let is_nullable = Self::get_nullability(&field.attrs)?;
+        let comment = doc_comment(&field.attrs);
+        Ok(Self {
+            ty,
+            default,
+            generated,
+            field_name,
+            name,
+            renamed_from,
+            comment,
+            is_nullable,
+        })
+    }
+
+    /// returns true if the field is not a column, as declared with `#[model(skip)]`.
+    pub fn is_skipped(field: &Field) -> Result<bool> {
+        if ModelAttrs::from_field(&field.attrs)?.skip.is_none() {
+            return Ok(false);
+        }
+        let column_attrs = [
+            "primary_key",
+            "foreign_key",
+            "unique",
+            "index",
+            "check",
+            "default",
+            "generated",
+            "nullable",
+            "not_null",
+        ];
+        for attr in &field.attrs {
+            if column_attrs.iter().any(|name| attr.path().is_ident(name)) {
+                return Err(Error::new_spanned(
+                    attr,
+                    "A field with `#[model(skip)]` is not a column.",
+                ));
+            }
+        }
+        Ok(true)
+    }
+
+    /// registers the objects the column depends on. It is called once the constraints
+    /// of the table are known, since some types replace them.
+    pub fn register(&self) -> TokenStream2 {
+        let col_name = &self.name;
+        let ty = &self.ty;
+        quote! {
+            <#ty as ::sql_from_models::types::IntoSQL>::register(&mut __models_table, #col_name);
+        }
+    }
+
+    /// name of the field in the Rust structure.
+    pub fn field_name(&self) -> &str {
+        &self.field_name
+    }
+
+    /// name of the column in the database.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_nullability(attrs: &[Attribute]) -> Result<Option<bool>> {
+        let mut out = None;
+        for attr in attrs {
+            let is_nullable = if attr.path().is_ident("nullable") {
+                true
+            } else if attr.path().is_ident("not_null") {
+                false
+            } else {
+                continue;
+            };
+            attr.meta.require_path_only()?;
+            if out.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Expected a single `#[nullable]` or `#[not_null]` attribute.",
+                ));
+            }
+            out = Some(is_nullable);
+        }
+        Ok(out)
     }
 
     fn get_default(attrs: Vec<Attribute>) -> Result<Option<DefaultExpr>> {
@@ -55,4 +170,20 @@ impl Column {
         }
         Ok(None)
     }
+
+    fn get_generated(attrs: &[Attribute]) -> Result<Option<Generated>> {
+        let mut out = None;
+        for attr in attrs {
+            if attr.path().is_ident("generated") {
+                if out.is_some() {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "Expected a single `#[generated]` attribute.",
+                    ));
+                }
+                out = Some(attr.parse_args()?);
+            }
+        }
+        Ok(out)
+    }
 }
@@ -14,38 +14,35 @@ pub struct NamedConstraint {
 }
 use std::fmt::Debug;
 
+use super::{column_const, Model};
 use crate::prelude::*;
+use syn::ext::IdentExt;
 
 #[derive(Default, Debug)]
 pub struct Unique {
     columns: Vec<Ident>,
 }
 impl ForeignKey {
-    fn into_tokens(&self, constr_name: &str, local_col: &Ident) -> TokenStream2 {
+    /// the name of the constraint ends with the referenced column,
+    /// which is only known once the referenced model is compiled.
+    fn into_tokens(&self, name_prefix: &str, local_col: &str, declared_on: &str) -> TokenStream2 {
         let foreign_col = &self.column;
-        let foreign_table = &self.foreign_table.get_ident();
+        let foreign_column = column_const(foreign_col.unraw());
+        let foreign_table = &self.foreign_table;
 
-        let on_update = self
-            .on_update
-            .clone()
-            .map(|x| x.value())
-            .unwrap_or_default();
-        let on_delete = self
-            .on_delete
-            .clone()
-            .map(|x| x.value())
-            .unwrap_or_default();
+        let options = &self.options;
         quote! {
+            let __models_constr_name = [#name_prefix, #foreign_table::#foreign_column].join("_");
             __models_table.constraints.push(
                 ::sql_from_models::private::constraint::foreign_key(
-                    #constr_name,
-                    stringify!(#local_col),
-                    stringify!(#foreign_table),
-                    stringify!(#foreign_col),
-                    #on_delete,
-                    #on_update,
+                    &__models_constr_name,
+                    #local_col,
+                    <#foreign_table as ::sql_from_models::private::Model>::table_name(),
+                    #foreign_table::#foreign_column,
+                    #options
                 )
             );
+            __models_table.declared_on.push((__models_constr_name, #declared_on));
             // Validation
             let _ = |__models_validation: #foreign_table| {
                 __models_validation.#foreign_col;
@@ -58,18 +55,20 @@ impl Unique {
     fn into_tokens(
         &self,
         constr_name: &str,
-        ty: &Ident,
+        model: &Model,
         field_name: &Ident,
         method: TokenStream2,
     ) -> TokenStream2 {
-        let columns = self.columns.iter();
+        let ty = &model.name;
+        let local_col = model.column_name(field_name);
+        let columns = self.columns.iter().map(|col| model.column_name(col));
         let columns1 = self.columns.iter();
 
         quote! {
             __models_table.constraints.push(
                 ::sql_from_models::private::constraint::#method(
                     #constr_name,
-                    &[stringify!(#field_name), #(stringify!(#columns)),*]
+                    &[#local_col, #(#columns),*]
                 )
             );
             let _ = |__models_validation: #ty| {
@@ -80,30 +79,37 @@ impl Unique {
 }
 
 impl NamedConstraint {
-    pub fn into_tokens(&self, ty: &Ident) -> TokenStream2 {
+    pub fn into_tokens(&self, model: &Model) -> TokenStream2 {
         match &self.constr {
             Constraint::ForeignKey(fk) => {
-                let constr_name = self.constr_name(ty, &[fk.column.clone()], "foreign");
-                fk.into_tokens(&constr_name, &self.field_name)
+                let name_prefix = self.constr_name(model, &[] as &[String], "foreign");
+                let declared_on = format!("{}::{}", model.name, self.field_name.unraw());
+                fk.into_tokens(
+                    &name_prefix,
+                    &model.column_name(&self.field_name),
+                    &declared_on,
+                )
             }
             Constraint::Primary(pk) => {
-                let constr_name = self.constr_name(ty, &pk.columns, "primary");
-                pk.into_tokens(&constr_name, ty, &self.field_name, quote!(primary))
+                let cols: Vec<_> = pk.columns.iter().map(|col| model.column_name(col)).collect();
+                let constr_name = self.constr_name(model, &cols, "primary");
+                pk.into_tokens(&constr_name, model, &self.field_name, quote!(primary))
             }
             Constraint::Unique(u) => {
-                let constr_name = self.constr_name(ty, &u.columns, "unique");
-                u.into_tokens(&constr_name, ty, &self.field_name, quote!(unique))
+                let cols: Vec<_> = u.columns.iter().map(|col| model.column_name(col)).collect();
+                let constr_name = self.constr_name(model, &cols, "unique");
+                u.into_tokens(&constr_name, model, &self.field_name, quote!(unique))
             }
         }
     }
 
-    pub fn constr_name(&self, ty: &Ident, cols: &[impl ToString], method: &str) -> String {
+    pub fn constr_name(&self, model: &Model, cols: &[impl ToString], method: &str) -> String {
         let mut constr_name = String::new();
-        constr_name += &ty.to_string().to_lowercase();
+        constr_name += model.table_name();
         constr_name += "_";
         constr_name += method;
         constr_name += "_";
-        constr_name += &self.field_name.to_string();
+        constr_name += &model.column_name(&self.field_name);
 
         for col in cols.iter() {
             constr_name += "_";
@@ -138,8 +144,7 @@ impl Parse for Unique {
 pub struct ForeignKey {
     foreign_table: Path,
     column: Ident,
-    on_delete: Option<LitStr>,
-    on_update: Option<LitStr>,
+    options: ForeignKeyOptions,
 }
 
 impl std::fmt::Debug for ForeignKey {
@@ -149,44 +154,224 @@ impl std::fmt::Debug for ForeignKey {
 }
 
 impl Parse for ForeignKey {
+    /// parses `Table.column, on_delete = "..."`. `Attribute::parse_args` already
+    /// strips the parentheses of `#[foreign_key(...)]`.
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let foreign_table = input.parse::<Path>()?;
+        input.parse::<Token![.]>()?;
+        let column = input.parse::<Ident>()?;
+        let options = ForeignKeyOptions::parse(input, |_| Ok(false))?;
+        Ok(ForeignKey {
+            foreign_table,
+            column,
+            options,
+        })
+    }
+}
+
+/// A foreign key spanning several columns, declared on the structure:
+/// `#[foreign_key(Table(a, b), columns(x, y), on_delete = "...")]`.
+pub struct CompositeForeignKey {
+    foreign_table: Path,
+    foreign_columns: Vec<Ident>,
+    columns: Vec<Ident>,
+    options: ForeignKeyOptions,
+}
+
+impl CompositeForeignKey {
+    /// parses the `#[foreign_key]` attributes of the structure.
+    pub fn from_struct(attrs: &[Attribute]) -> Result<Vec<Self>> {
+        let mut out = vec![];
+        for attr in attrs {
+            if attr.path().is_ident("foreign_key") {
+                out.push(attr.parse_args()?);
+            }
+        }
+        Ok(out)
+    }
+
+    /// the fields of the model the foreign key spans.
+    pub fn local_fields(&self) -> &[Ident] {
+        &self.columns
+    }
+
+    pub fn tokens(&self, model: &Model) -> TokenStream2 {
+        let ty = &model.name;
+        let foreign_table = &self.foreign_table;
+        let local_cols: Vec<_> = self
+            .columns
+            .iter()
+            .map(|col| model.column_name(col))
+            .collect();
+        // the referenced columns are appended once the referenced model is compiled.
+        let mut name_prefix = format!("{}_foreign", model.table_name());
+        for col in &local_cols {
+            name_prefix += "_";
+            name_prefix += col;
+        }
+
+        let declared_on = ty.to_string();
+        let foreign_consts: Vec<_> = self
+            .foreign_columns
+            .iter()
+            .map(|col| column_const(col.unraw()))
+            .collect();
+        let options = &self.options;
+        let columns = &self.columns;
+        let foreign_columns = &self.foreign_columns;
+        quote! {
+            let __models_constr_name =
+                [#name_prefix, #(#foreign_table::#foreign_consts),*].join("_");
+            __models_table.constraints.push(
+                ::sql_from_models::private::constraint::composite_foreign_key(
+                    &__models_constr_name,
+                    &[#(#local_cols),*],
+                    <#foreign_table as ::sql_from_models::private::Model>::table_name(),
+                    &[#(#foreign_table::#foreign_consts),*],
+                    #options
+                )
+            );
+            __models_table.declared_on.push((__models_constr_name, #declared_on));
+            // Validation
+            let _ = |__models_validation: #foreign_table| {
+                #(__models_validation.#foreign_columns;)*
+            };
+            let _ = |__models_validation: #ty| {
+                #(__models_validation.#columns;)*
+            };
+        }
+    }
+}
+
+impl Parse for CompositeForeignKey {
     fn parse(input: parse::ParseStream) -> Result<Self> {
-        let content;
-        let _paren = parenthesized!(content in input);
+        let foreign_table = input.parse::<Path>()?;
+        let foreign_columns = parse_columns(input)?;
+        let mut columns = None;
+        let options = ForeignKeyOptions::parse(input, |ident| {
+            if ident != "columns" {
+                return Ok(false);
+            }
+            if columns.is_some() {
+                return Err(Error::new(ident.span(), "Expected a single `columns`."));
+            }
+            columns = Some(parse_columns(input)?);
+            Ok(true)
+        })?;
+        let columns = columns.ok_or_else(|| {
+            Error::new(
+                foreign_table.span(),
+                "Expected `columns(...)` listing the local columns of the foreign key.",
+            )
+        })?;
+        if columns.len() != foreign_columns.len() {
+            return Err(Error::new(
+                foreign_table.span(),
+                format!(
+                    "Expected as many local columns as referenced columns. Found {} and {}.",
+                    columns.len(),
+                    foreign_columns.len()
+                ),
+            ));
+        }
+        Ok(CompositeForeignKey {
+            foreign_table,
+            foreign_columns,
+            columns,
+            options,
+        })
+    }
+}
 
-        let foreign_table = content.parse::<Path>()?;
-        content.parse::<Token![.]>()?;
-        let mut on_delete = None;
-        let mut on_update = None;
-        let column = content.parse::<Ident>()?;
-        while content.parse::<Token![,]>().is_ok() {
-            let ident: Ident = content.parse()?;
+/// parses a non-empty parenthesized list of fields, such as `(a, b)`.
+pub(super) fn parse_columns(input: parse::ParseStream) -> Result<Vec<Ident>> {
+    let content;
+    let paren = parenthesized!(content in input);
+    let columns: Vec<Ident> = content
+        .parse_terminated(Ident::parse, Token![,])?
+        .into_iter()
+        .collect();
+    if columns.is_empty() {
+        return Err(Error::new(paren.span.join(), "Expected at least one column."));
+    }
+    Ok(columns)
+}
+
+/// The `on_delete`, `on_update` and `deferrable` options of a foreign key.
+#[derive(Default)]
+struct ForeignKeyOptions {
+    on_delete: Option<LitStr>,
+    on_update: Option<LitStr>,
+    deferrable: Option<LitStr>,
+}
+
+impl ForeignKeyOptions {
+    /// parses the trailing `, on_delete = "..."`, `, on_update = "..."` and `, deferrable` options.
+    /// `other` is given the remaining options and returns whether it parsed them.
+    fn parse(
+        input: parse::ParseStream,
+        mut other: impl FnMut(&Ident) -> Result<bool>,
+    ) -> Result<Self> {
+        let mut out = Self::default();
+        while input.parse::<Token![,]>().is_ok() {
+            let ident: Ident = input.parse()?;
             if ident == "on_delete" {
-                content.parse::<Token![=]>()?;
-                if on_delete.is_some() {
+                input.parse::<Token![=]>()?;
+                if out.on_delete.is_some() {
                     return Err(Error::new(ident.span(), "Expected a single `on_delete`."));
                 }
-                on_delete = Some(content.parse()?);
+                out.on_delete = Some(input.parse()?);
             } else if ident == "on_update" {
-                content.parse::<Token![=]>()?;
-                if on_update.is_some() {
+                input.parse::<Token![=]>()?;
+                if out.on_update.is_some() {
                     return Err(Error::new(ident.span(), "Expected a single `on_update`."));
                 }
-                on_update = Some(content.parse()?);
-            } else {
+                out.on_update = Some(input.parse()?);
+            } else if ident == "deferrable" {
+                if out.deferrable.is_some() {
+                    return Err(Error::new(ident.span(), "Expected a single `deferrable`."));
+                }
+                // `deferrable` alone leaves the constraint initially immediate.
+                out.deferrable = if input.parse::<Token![=]>().is_ok() {
+                    Some(input.parse()?)
+                } else {
+                    Some(LitStr::new("initially immediate", ident.span()))
+                };
+            } else if !other(&ident)? {
                 return Err(Error::new(
                     ident.span(),
-                    "Expected `on_delete` or `on_update`.",
+                    "Expected `on_delete`, `on_update` or `deferrable`.",
                 ));
             }
         }
-        is_valid(&on_delete)?;
-        is_valid(&on_update)?;
-        Ok(ForeignKey {
-            foreign_table,
-            column,
-            on_delete,
-            on_update,
-        })
+        is_valid(&out.on_delete)?;
+        is_valid(&out.on_update)?;
+        if let Some(deferrable) = &out.deferrable {
+            if !matches!(
+                &*deferrable.value(),
+                "initially immediate" | "initially deferred"
+            ) {
+                return Err(Error::new(
+                    deferrable.span(),
+                    format!(
+                        "invalid deferrable constraint. Found {:?}, expected one of: {:?}",
+                        deferrable.value(),
+                        ["initially immediate", "initially deferred"],
+                    ),
+                ));
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl ToTokens for ForeignKeyOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let value = |option: &Option<LitStr>| option.as_ref().map(LitStr::value).unwrap_or_default();
+        let on_delete = value(&self.on_delete);
+        let on_update = value(&self.on_update);
+        let deferrable = value(&self.deferrable);
+        tokens.extend(quote!(#on_delete, #on_update, #deferrable,));
     }
 }
 
@@ -245,6 +430,13 @@ impl Constraints {
 
 
 impl Constraint {
+    /// the other fields of the model the constraint spans, besides the one it is declared on.
+    pub fn local_fields(&self) -> &[Ident] {
+        match self {
+            Constraint::Primary(unique) | Constraint::Unique(unique) => &unique.columns,
+            Constraint::ForeignKey(_) => &[],
+        }
+    }
     pub fn column_names(&self) -> Vec<Ident> {
         match &self {
             Constraint::Primary(primary) => primary.columns.to_vec(),
@@ -271,7 +463,7 @@ fn is_valid(on_delete: &Option<LitStr>) -> Result<()> {
     if let Some(string) = on_delete {
         return if matches!(
             &*string.value(),
-            "cascade" | "set null" | "restrict" | "no action"
+            "cascade" | "set null" | "set default" | "restrict" | "no action"
         ) {
             Ok(())
         } else {
@@ -280,7 +472,7 @@ fn is_valid(on_delete: &Option<LitStr>) -> Result<()> {
                 format!(
                     "invalid referential integrity constraint. Found {:?}, expected one of: {:?}",
                     string.value(),
-                    ["restrict", "cascade", "set null", "no action"],
+                    ["restrict", "cascade", "set null", "set default", "no action"],
                 ),
             ))
         }
@@ -0,0 +1,107 @@
+use super::{constraint::parse_columns, Model};
+use crate::prelude::*;
+
+/// An index declared with `#[index(...)]`.
+/// On a field the index starts with the field's column,
+/// while on the structure at least one column has to be listed.
+#[derive(Default)]
+pub struct Index {
+    field: Option<Ident>,
+    columns: Vec<Ident>,
+    name: Option<LitStr>,
+    unique: bool,
+}
+
+impl Index {
+    /// parses the `#[index]` attributes of the structure.
+    pub fn from_struct(attrs: &[Attribute]) -> Result<Vec<Self>> {
+        let mut out = vec![];
+        for attr in attrs {
+            if attr.path().is_ident("index") {
+                let index: Index = attr.parse_args()?;
+                if index.columns.is_empty() {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "Expected at least one column. help: `#[index(first, second)]`",
+                    ));
+                }
+                out.push(index);
+            }
+        }
+        Ok(out)
+    }
+
+    /// parses the `#[index]` attributes of a field.
+    pub fn from_field(field: &Field) -> Result<Vec<Self>> {
+        let mut out = vec![];
+        for attr in &field.attrs {
+            if attr.path().is_ident("index") {
+                let mut index = match &attr.meta {
+                    Meta::Path(_) => Index::default(),
+                    _ => attr.parse_args()?,
+                };
+                index.field = field.ident.clone();
+                out.push(index);
+            }
+        }
+        Ok(out)
+    }
+
+    /// the fields the index spans, starting with the one it is declared on.
+    pub fn fields(&self) -> impl Iterator<Item = &Ident> {
+        self.field.iter().chain(&self.columns)
+    }
+
+    pub fn tokens(&self, model: &Model) -> TokenStream2 {
+        let ty = &model.name;
+        let table_name = model.table_name();
+        let columns: Vec<_> = self.fields().map(|field| model.column_name(field)).collect();
+        let name = match &self.name {
+            Some(name) => name.value().to_lowercase(),
+            None => model.constr_name(&"index", &columns[0], &columns[1..]),
+        };
+        let unique = self.unique;
+        let fields = self.fields();
+        quote! {
+            __models_table.indexes.push(
+                ::sql_from_models::private::index::create(
+                    #name,
+                    #table_name,
+                    &[#(#columns),*],
+                    #unique,
+                )
+            );
+            let _ = |__models_validation: #ty| {
+                #(__models_validation.#fields;)*
+            };
+        }
+    }
+}
+
+impl Parse for Index {
+    /// parses `a, b, name = "...", unique`. Fields named `name` or `unique`
+    /// are listed with `columns(name, unique)`.
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let mut out = Index::default();
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            if ident == "columns" && input.peek(token::Paren) {
+                out.columns.extend(parse_columns(input)?);
+            } else if ident == "name" && input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                if out.name.is_some() {
+                    return Err(Error::new(ident.span(), "Expected a single `name`."));
+                }
+                out.name = Some(input.parse()?);
+            } else if ident == "unique" {
+                out.unique = true;
+            } else {
+                out.columns.push(ident);
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(out)
+    }
+}
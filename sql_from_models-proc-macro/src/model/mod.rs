@@ -1,16 +1,34 @@
+mod attributes;
+mod check;
 mod column;
 mod constraint;
+mod index;
 use crate::prelude::*;
+pub(crate) use attributes::{doc_comment, ModelAttrs, RenameRule};
 use constraint::*;
+use syn::ext::IdentExt;
 use Data::*;
 
-use self::column::Column;
+use self::{check::Check, column::Column, index::Index};
+
+/// name of the constant the derive adds to the structure with the column of `field`.
+/// Foreign keys refer to it, so referencing a field without a column does not compile.
+pub(crate) fn column_const(field: impl fmt::Display) -> Ident {
+    format_ident!("__models_column_{}", field.to_string())
+}
+
 pub struct Model {
     pub name: Ident,
-    name_lowercase: Ident,
+    table_name: String,
+    rename_all: RenameRule,
+    renamed_from: Option<String>,
+    comment: Option<String>,
     data: DataStruct,
     columns: Vec<Column>,
     constraints: Vec<NamedConstraint>,
+    indexes: Vec<Index>,
+    checks: Vec<Check>,
+    foreign_keys: Vec<CompositeForeignKey>,
 }
 
 struct ForeignKey {
@@ -38,15 +56,31 @@ impl Parse for Model {
     fn parse(input: parse::ParseStream) -> Result<Self> {
         let input: DeriveInput = input.parse()?;
         let name = input.ident;
-        let name_lowercase = Ident::new(&name.to_string().to_lowercase(), name.span());
+        let attrs = ModelAttrs::from_struct(&input.attrs)?;
+        let rename_all = attrs.rename_rule()?;
+        let table_name = match &attrs.table {
+            Some(table) => table.value().to_lowercase(),
+            None => rename_all.apply(&name.unraw().to_string()),
+        };
+        let renamed_from = attrs.renamed_from.map(|name| name.value().to_lowercase());
+        let comment = doc_comment(&input.attrs);
+        let indexes = Index::from_struct(&input.attrs)?;
+        let checks = Check::from_struct(&input.attrs)?;
+        let foreign_keys = CompositeForeignKey::from_struct(&input.attrs)?;
         match input.data {
             Struct(data) => {
                 let mut model = Self {
                     name,
                     data,
-                    name_lowercase,
+                    table_name,
+                    rename_all,
+                    renamed_from,
+                    comment,
                     columns: Default::default(),
                     constraints: Default::default(),
+                    indexes,
+                    checks,
+                    foreign_keys,
                 };
                 model.init()?;
                 Ok(model)
@@ -59,17 +93,41 @@ impl Parse for Model {
 impl ToTokens for Model {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let name = &self.name;
-        let name_lowercase = &self.name_lowercase;
+        let table_name = &self.table_name;
         let columns = &self.get_columns();
         let constraints = &self.get_constraints();
+        let indexes = &self.get_indexes();
+        let registers = self.columns.iter().map(Column::register);
+        let renamed_from = self.renamed_from.iter();
+        let comment = self.comment.iter();
+        let column_consts = self
+            .columns
+            .iter()
+            .map(|col| column_const(col.field_name()));
+        let const_values = self.columns.iter().map(Column::name);
         let template = quote! {
+          // the columns referenced by foreign keys, so fields without a column do not compile.
+          impl #name {
+            #(
+                #[doc(hidden)]
+                #[allow(non_upper_case_globals, dead_code)]
+                pub const #column_consts: &'static str = #const_values;
+            )*
+          }
           impl ::sql_from_models::private::Model for #name {
             fn target() -> ::sql_from_models::private::Table {
-                let mut __models_table = ::sql_from_models::private::Table::new(stringify!(#name_lowercase));
+                let mut __models_table = ::sql_from_models::private::Table::new(#table_name);
+                #(__models_table.renamed_from = Some(#renamed_from.into());)*
+                #(__models_table.comment = Some(#comment.into());)*
                 #columns
                 #constraints
+                #indexes
+                #(#registers)*
                 __models_table
             }
+            fn table_name() -> &'static str {
+                #table_name
+            }
           }
         };
         tokens.extend(template);
@@ -79,25 +137,117 @@ impl ToTokens for Model {
 impl Model {
     // include
     fn init(&mut self) -> Result<()> {
+        self.init_columns()?;
+        self.check_fields()
+    }
+
+    fn init_columns(&mut self) -> Result<()> {
+        let mut fields = vec![];
         for field in &self.data.fields {
-            let col_name = field.ident.clone().unwrap();
+            if !Column::is_skipped(field)? {
+                fields.push(field);
+            }
+        }
+        for &field in &fields {
+            let column = Column::new(field, self.rename_all)?;
+            self.columns.push(column);
+        }
+        for field in fields {
+            let field_name = field.ident.clone().unwrap();
             let constrs: Vec<_> = Constraints::from_attrs(&field.attrs)?
                 .0
                 .into_iter()
                 .map(|constr| NamedConstraint {
-                    name: self.constr_name(&constr.method(), &col_name, &constr.column_names()),
-                    field_name: col_name.clone(),
+                    name: self.constr_name(
+                        &constr.method(),
+                        &self.column_name(&field_name),
+                        &constr.column_names(),
+                    ),
+                    field_name: field_name.clone(),
                     constr,
                 })
                 .collect();
             self.constraints.extend(constrs);
+            self.indexes.extend(Index::from_field(field)?);
+            self.checks.extend(Check::from_field(field)?);
+        }
+        Ok(())
+    }
 
-            let column = Column::new(field)?;
-            self.columns.push(column);
+    /// returns an error if an attribute names a field without a column,
+    /// such as the fields skipped with `#[model(skip)]`.
+    fn check_fields(&self) -> Result<()> {
+        let fields = self
+            .constraints
+            .iter()
+            .flat_map(|constr| constr.constr.local_fields())
+            .chain(self.indexes.iter().flat_map(Index::fields))
+            .chain(self.foreign_keys.iter().flat_map(|fk| fk.local_fields()));
+        for field in fields {
+            if self.column(field).is_none() {
+                let message = if self.skipped(&field.unraw().to_string()) {
+                    format!(
+                        "`{}` is skipped with `#[model(skip)]`, so it has no column.",
+                        field
+                    )
+                } else {
+                    format!("`{}` is not a field of `{}`.", field, self.name)
+                };
+                return Err(Error::new(field.span(), message));
+            }
+        }
+        for check in &self.checks {
+            for field in self
+                .data
+                .fields
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+            {
+                let field = field.unraw().to_string();
+                let column = self.rename_all.apply(&field);
+                let is_column = self.columns.iter().any(|col| col.name() == column);
+                if self.skipped(&field) && !is_column && check.mentions(&column) {
+                    return Err(Error::new(
+                        check.expr().span(),
+                        format!(
+                            "`{}` is skipped with `#[model(skip)]`, so it has no column.",
+                            field
+                        ),
+                    ));
+                }
+            }
         }
         Ok(())
     }
 
+    fn column(&self, field: &Ident) -> Option<&Column> {
+        self.columns
+            .iter()
+            .find(|col| field.unraw() == col.field_name())
+    }
+
+    /// returns true if the structure has a field named `field` that is not a column.
+    fn skipped(&self, field: &str) -> bool {
+        let is_field = self
+            .data
+            .fields
+            .iter()
+            .any(|f| f.ident.as_ref().map(|ident| ident.unraw() == field) == Some(true));
+        is_field && !self.columns.iter().any(|col| col.field_name() == field)
+    }
+
+    /// returns the name of the column the field is mapped to.
+    /// The fields are checked by `Model::init`, so they all have a column.
+    pub fn column_name(&self, field: &Ident) -> String {
+        self.column(field)
+            .map(|col| col.name().to_string())
+            .unwrap_or_else(|| panic!("`{}` has no column.", field))
+    }
+
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
     fn get_columns(&self) -> TokenStream2 {
         let columns = self.columns.iter();
         quote! {
@@ -109,9 +259,16 @@ impl Model {
         let columns = self
             .constraints
             .iter()
-            .map(|constr| constr.into_tokens(&self.name));
+            .map(|constr| constr.into_tokens(self));
+        let foreign_keys = self.foreign_keys.iter().map(|fk| fk.tokens(self));
+        let checks = self.checks.iter().map(|check| check.tokens(self));
+
+        quote! {#(#columns;)* #(#foreign_keys)* #(#checks)*}
+    }
 
-        quote! {#(#columns;)*}
+    fn get_indexes(&self) -> TokenStream2 {
+        let indexes = self.indexes.iter().map(|index| index.tokens(self));
+        quote! {#(#indexes)*}
     }
 
     pub fn constr_name(
@@ -121,7 +278,7 @@ impl Model {
         cols: &[impl ToString],
     ) -> String {
         let mut constr_name = String::new();
-        constr_name += &self.name_lowercase.to_string();
+        constr_name += &self.table_name;
         constr_name += "_";
         constr_name += &method.to_string();
         constr_name += "_";
@@ -0,0 +1,64 @@
+use crate::model::{ModelAttrs, RenameRule};
+use crate::prelude::*;
+use syn::ext::IdentExt;
+
+/// An enum stored as a native SQL enum.
+pub struct SqlEnum {
+    input: DeriveInput,
+    type_name: String,
+    variants: Vec<String>,
+}
+
+impl Parse for SqlEnum {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let input: DeriveInput = input.parse()?;
+        let attrs = ModelAttrs::from_struct(&input.attrs)?;
+        if let Some(option) = attrs.table.as_ref().or(attrs.renamed_from.as_ref()) {
+            return Err(Error::new(
+                option.span(),
+                "Expected `rename_all`. Enums only support a naming policy.",
+            ));
+        }
+        let rename_all: RenameRule = attrs.rename_rule()?;
+        let data = match &input.data {
+            Data::Enum(data) => data,
+            _ => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "SqlEnum can only be derived for enums.",
+                ))
+            }
+        };
+        let mut variants = vec![];
+        for variant in &data.variants {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(Error::new(
+                    variant.ident.span(),
+                    "SqlEnum variants cannot have fields.",
+                ));
+            }
+            variants.push(rename_all.apply(&variant.ident.unraw().to_string()));
+        }
+        let type_name = rename_all.apply(&input.ident.unraw().to_string());
+        Ok(Self {
+            input,
+            type_name,
+            variants,
+        })
+    }
+}
+
+impl ToTokens for SqlEnum {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let name = &self.input.ident;
+        let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
+        let type_name = &self.type_name;
+        let variants = &self.variants;
+        tokens.extend(quote! {
+            impl #impl_generics ::sql_from_models::types::SqlEnum for #name #ty_generics #where_clause {
+                const NAME: &'static str = #type_name;
+                const VARIANTS: &'static [&'static str] = &[#(#variants),*];
+            }
+        });
+    }
+}
@@ -1,8 +1,11 @@
 use self::Dialect::*;
 use dialect::*;
 use sql_from_models_parser::dialect;
-#[derive(Clone, Copy, Debug)]
-pub(crate) enum Dialect {
+use url::Url;
+
+/// SQL dialect the migrations are generated for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
     SQLite,
     PostgreSQL,
     MySQL,
@@ -11,6 +14,29 @@ pub(crate) enum Dialect {
 }
 
 impl Dialect {
+    /// the dialect of the database at `url`.
+    pub(crate) fn from_url(url: &Url) -> Self {
+        match url.scheme() {
+            "sqlite" => SQLite,
+            "postgres" => PostgreSQL,
+            "mysql" => MySQL,
+            "mssql" => MsSQL,
+            _ => Any,
+        }
+    }
+
+    /// the dialect of the parser, so dialect specific syntax such as
+    /// MySQL's `AUTO_INCREMENT` is recognized when migrations are replayed.
+    pub(crate) fn parser(&self) -> Box<dyn dialect::Dialect> {
+        match self {
+            SQLite => Box::new(SQLiteDialect {}),
+            PostgreSQL => Box::new(PostgreSqlDialect {}),
+            MySQL => Box::new(MySqlDialect {}),
+            MsSQL => Box::new(MsSqlDialect {}),
+            Any => Box::new(GenericDialect {}),
+        }
+    }
+
     pub(crate) fn requires_move(&self) -> bool {
         matches!(self, Dialect::SQLite | Dialect::Any)
     }
@@ -19,9 +45,34 @@ impl Dialect {
         matches!(self, Dialect::PostgreSQL)
     }
 
+    /// columns can be altered in place instead of moving the table.
+    pub(crate) fn alters_columns(&self) -> bool {
+        matches!(self, PostgreSQL | MySQL)
+    }
+
     pub(crate) fn supports_cascade(&self) -> bool {
         !matches!(self, SQLite)
     }
+
+    /// InnoDB rejects foreign keys with `SET DEFAULT` actions.
+    pub(crate) fn supports_set_default(&self) -> bool {
+        !matches!(self, MySQL)
+    }
+
+    /// generated columns can be computed when they are read instead of being stored.
+    pub(crate) fn supports_virtual_columns(&self) -> bool {
+        !matches!(self, PostgreSQL)
+    }
+
+    /// tables and columns can be commented.
+    pub(crate) fn supports_comments(&self) -> bool {
+        matches!(self, PostgreSQL | MySQL)
+    }
+
+    /// foreign keys can be `DEFERRABLE`.
+    pub(crate) fn supports_deferrable(&self) -> bool {
+        !matches!(self, MySQL | MsSQL)
+    }
 }
 
 impl dialect::Dialect for Dialect {
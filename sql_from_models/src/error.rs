@@ -19,6 +19,8 @@ pub enum Error {
     SyntaxAtFile(ParserError, path::PathBuf),
     #[error("{0}")]
     Message(String),
+    #[error("could not read the database schema. {0}")]
+    Database(String),
     #[error("could not read or create migration file. {0}")]
     IO(#[from] Arc<io::Error>),
     #[error("dependency cycle detected invlonving the tables: {0:?}. help: consider removing redundant foreign key constraints. ")]
@@ -31,20 +33,21 @@ impl Error {
             Self::Cycle(_) => "CycleError",
             Self::Message(_) => "error",
             Self::IO(_) => "IOError",
+            Self::Database(_) => "DatabaseError",
             Self::Syntax(_) => "SyntaxError",
             Self::SyntaxAtFile(_, _) => "SyntaxAtFile",
         }
     }
+}
 
-    pub(crate) fn as_json(&self) -> String {
-        let err_msg = format!("{}", self);
-        let kind = self.kind();
-
-        format!(
-            r#"{{"kind":{kind:?},"message":{message:?}}}"#,
-            kind = kind,
-            message = err_msg
-        )
+/// serialized as `{"kind": ..., "message": ...}`, the format read by the CLI.
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut error = serializer.serialize_struct("Error", 2)?;
+        error.serialize_field("kind", self.kind())?;
+        error.serialize_field("message", &self.to_string())?;
+        error.end()
     }
 }
 
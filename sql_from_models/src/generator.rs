@@ -0,0 +1,149 @@
+//! Generation of migrations from Rust code, without the CLI or environment variables.
+use crate::{
+    prelude::*,
+    private::scheduler::driver::{Difference, Driver},
+    settings::{self, Settings},
+};
+#[cfg(feature = "sqlformat")]
+use sqlformat::FormatOptions;
+
+/// Plans the migrations of a list of tables for a dialect.
+/// The current schema is read from the migrations directory, or from a live database.
+/// ```ignore
+/// use sql_from_models::{Dialect, Generator};
+///
+/// let generator = Generator::new(Dialect::PostgreSQL)
+///     .migrations_dir("migrations")
+///     .reversible(true);
+/// let migrations = generator.generate(vec![generator.target::<Post>()])?;
+/// for migration in &migrations {
+///     println!("{}:\n{}", migration.name(), migration.up_sql());
+/// }
+/// ```
+/// Generators for different dialects can be used in the same process.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    settings: Settings,
+    tables: Option<Vec<String>>,
+}
+
+impl Generator {
+    pub fn new(dialect: Dialect) -> Self {
+        Self {
+            settings: Settings::new(dialect),
+            tables: None,
+        }
+    }
+
+    /// folder with the migrations that have already been generated.
+    /// Without it the tables are compared against an empty schema.
+    pub fn migrations_dir(mut self, dir: impl Into<String>) -> Self {
+        self.settings.migrations_dir = Some(dir.into());
+        self
+    }
+
+    /// reads the current schema from the database at `url` instead of the migrations directory.
+    /// It requires one of the `sqlx-postgres`, `sqlx-mysql` or `sqlx-sqlite` features.
+    pub fn from_database(mut self, url: impl Into<String>) -> Self {
+        self.settings.from_database = Some(url.into());
+        self
+    }
+
+    /// generates down migrations along with up migrations.
+    pub fn reversible(mut self, reversible: bool) -> Self {
+        self.settings.reversible = reversible;
+        self
+    }
+
+    /// adds a `CHECK (col >= 0)` constraint to the columns of unsigned integers on PostgreSQL,
+    /// which does not have unsigned types.
+    pub fn unsigned_checks(mut self, unsigned_checks: bool) -> Self {
+        self.settings.unsigned_checks = unsigned_checks;
+        self
+    }
+
+    /// options used to format the SQL of the migrations. `None` leaves it unformatted.
+    #[cfg(feature = "sqlformat")]
+    pub fn format(mut self, options: Option<FormatOptions<'static>>) -> Self {
+        self.settings.format = options;
+        self
+    }
+
+    /// only generates migrations for the tables with these names.
+    pub fn filter_tables<S: Into<String>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+        let names = names.into_iter().map(|name| name.into().to_lowercase());
+        self.tables = Some(names.collect());
+        self
+    }
+
+    /// the table of a model, with the data types of the dialect of the generator.
+    pub fn target<M: Model>(&self) -> Table {
+        settings::scope(self.settings.clone(), M::target)
+    }
+
+    /// returns the migrations that bring the current schema to `tables`, in the order they must be applied.
+    /// Nothing is written to the migrations directory.
+    pub fn generate(&self, tables: impl IntoIterator<Item = Table>) -> Result<Vec<Migration>> {
+        settings::scope(self.settings.clone(), || {
+            let mut driver = Driver::new();
+            for table in tables {
+                if self.includes(&table) {
+                    driver.register(table);
+                }
+            }
+            driver.plan()
+        })
+    }
+
+    /// renders the statements that would be generated, grouped by table, without writing them.
+    /// Down statements are included for reversible generators.
+    /// ```sql
+    /// -- post
+    /// ALTER TABLE post ADD COLUMN title TEXT NOT NULL;
+    /// -- post (down)
+    /// ALTER TABLE post DROP COLUMN title;
+    /// ```
+    pub fn dry_run(&self, tables: impl IntoIterator<Item = Table>) -> Result<String> {
+        let mut out = String::new();
+        for difference in self.differences(tables)? {
+            out += &format!("-- {}\n", difference.name);
+            for stmt in &difference.up {
+                out += &format!("{};\n", stmt);
+            }
+            if !difference.down.is_empty() {
+                out += &format!("-- {} (down)\n", difference.name);
+            }
+            for stmt in &difference.down {
+                out += &format!("{};\n", stmt);
+            }
+        }
+        Ok(out)
+    }
+
+    /// same as `dry_run`, as a JSON array with the table name, and the up and down statements of each table.
+    /// It requires the `json` feature.
+    /// ```json
+    /// [["post",["ALTER TABLE post ADD COLUMN title TEXT NOT NULL"],[]]]
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn dry_run_json(&self, tables: impl IntoIterator<Item = Table>) -> Result<String> {
+        serde_json::to_string(&self.differences(tables)?).map_err(|err| error!("{}", err))
+    }
+
+    fn differences(&self, tables: impl IntoIterator<Item = Table>) -> Result<Vec<Difference>> {
+        let mut out = vec![];
+        for migration in self.generate(tables)? {
+            if let Some(difference) = migration.difference() {
+                Difference::push(&mut out, difference);
+            }
+        }
+        Ok(out)
+    }
+
+    fn includes(&self, table: &Table) -> bool {
+        match &self.tables {
+            Some(names) => names.contains(&table.name.to_string()),
+            None => true,
+        }
+    }
+}
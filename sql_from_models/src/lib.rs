@@ -93,6 +93,7 @@
 //! Generated: migrations/1632280793476 commentlike
 //! ```
 //! You can check out the generated migrations at the `migrations/` folder.
+//! The models register themselves when the crate is linked, including the models of its dependencies. The CLI builds a binary that links every library of the workspace depending on `sql_from_models` and runs it to generate the migrations, so no code needs to be added to `main`. Models must therefore be declared in a library target: the ones declared only in a binary are not found.
 //! To execute these migrations you can execute the following command:
 //! ```ignore
 //! sql_from_models migrate run
@@ -120,6 +121,79 @@
 //! ```ignore
 //! $ sql_from_models migrate info
 //! ```
+//! ## Dry run
+//! The `--dry-run` flag prints the statements that would be generated, grouped by table, without writing any file.
+//! Down statements are included with `-r`, and `--json` prints them as JSON:
+//! ```ignore
+//! $ sql_from_models generate --dry-run --json
+//! ```
+//! The same output is returned by `Generator::dry_run` and, with the `json` feature, `Generator::dry_run_json`.
+//!
+//! ## Generating from the database
+//! By default the current state of the tables is read from the migrations folder.
+//! When the database was modified by hand, or migrations were lost, the `--from-database` flag reads the tables of the database at `DATABASE_URL` instead:
+//! ```ignore
+//! $ sql_from_models generate --from-database
+//! ```
+//! The crate must be compiled with the `sqlx-postgres`, `sqlx-mysql` or `sqlx-sqlite` feature matching the database, and an sqlx runtime feature.
+//!
+//! ## Unsigned integers
+//! Unsigned integers are `UNSIGNED` on MySQL. PostgreSQL has no unsigned types, so `u8`, `u16`, `u32` and `u64` are stored in `SMALLINT`, `INT`, `BIGINT` and `NUMERIC(20,0)`.
+//! The `--unsigned-checks` flag (or `Generator::unsigned_checks`) also adds a `CHECK (col >= 0)` constraint to their columns:
+//! ```ignore
+//! $ sql_from_models generate --unsigned-checks
+//! ```
+//! It must be passed to `check` as well, so the constraints are not reported as differences.
+//!
+//! ## Auto-increment
+//! `Serial` and `BigSerial` are `SERIAL` and `BIGSERIAL` on PostgreSQL, `AUTO_INCREMENT` integers on MySQL,
+//! and `INTEGER PRIMARY KEY AUTOINCREMENT` on SQLite, where they replace the primary key declared on the column:
+//! ```ignore
+//! #[derive(Model)]
+//! struct Ticket {
+//!     #[primary_key]
+//!     id: Serial,
+//! }
+//! ```
+//! MySQL requires `AUTO_INCREMENT` columns to be part of a key, and SQLite requires them to be
+//! the single primary key of the table: generating a migration for any other column fails.
+//!
+//! ## Comments
+//! Doc comments on the structure and its fields become the comments of the table and its columns.
+//! They are set with `COMMENT ON` on PostgreSQL and declared inline on MySQL, and changing them generates a migration:
+//! ```ignore
+//! /// Invoices sent to customers.
+//! #[derive(Model)]
+//! struct Invoice {
+//!     /// Total in cents, taxes included.
+//!     total: i64,
+//! }
+//! ```
+//! SQLite does not support comments, so they are ignored.
+//!
+//! ## Generating from Rust code
+//! Migrations can also be planned from build scripts, tests or other tools with a `Generator`, without the CLI or environment variables.
+//! It returns the migrations in memory, and generators for different dialects can be used in the same process:
+//! ```ignore
+//! use sql_from_models::{Dialect, Generator};
+//!
+//! let generator = Generator::new(Dialect::PostgreSQL)
+//!     .migrations_dir("migrations")
+//!     .reversible(true)
+//!     .filter_tables(vec!["post"]);
+//! let migrations = generator.generate(vec![generator.target::<Post>()])?;
+//! for migration in &migrations {
+//!     println!("{}:\n{}", migration.name(), migration.up_sql());
+//! }
+//! ```
+//!
+//! ## Checking for drift
+//! The `check` command (or `diff`) lists the statements each table needs to match its model, without writing any migration.
+//! It exits with an error when there are differences, so it can be used in CI to catch models changed without running `generate`:
+//! ```ignore
+//! $ sql_from_models check
+//! ```
+//! With `--from-database` the models are compared against the tables of the database instead of the migrations folder.
 //! ## Avaibale Attributes
 //! ### primary_key
 //! It's used to mark the primary key fo the table.
@@ -153,15 +227,77 @@
 //! ```sql
 //!     FOREIGN KEY (profile_id) REFERENCES profile (id) ON DELETE CASCADE,
 //! ```
+//! `on_delete` and `on_update` accept `"cascade"`, `"set null"`, `"set default"`, `"restrict"` and `"no action"`.
+//! Foreign keys can also be `deferrable`. A bare `deferrable` is `deferrable="initially immediate"`,
+//! so `deferrable="initially deferred"` is needed to check them at the end of the transaction:
+//! ```ignore
+//!     #[foreign_key(Profile.id, on_delete="set default", deferrable="initially deferred")]
+//!     profile_id: i32,
+//! ```
+//! Options the dialect does not support are reported with the field they are declared on when the migrations
+//! are generated: MySQL rejects `set default` and `deferrable`. SQLite only enforces foreign keys
+//! on connections that enable `PRAGMA foreign_keys = ON`, which the CLI prints a warning about.
+//! Foreign keys referencing several columns are declared on the structure, pairing the
+//! referenced columns with the local ones listed in `columns`:
+//! ```ignore
+//! #[derive(Model)]
+//! #[foreign_key(PostLike(profile_id, post_id), columns(profile, post), on_delete="cascade")]
+//! struct LikeNotification {
+//!     profile: i32,
+//!     post: i32,
+//! }
+//! ```
+//! This is equivalent to:
+//! ```sql
+//!     FOREIGN KEY (profile, post) REFERENCES postlike (profile_id, post_id) ON DELETE CASCADE,
+//! ```
 //! ### default
 //! It can be used to set a default value for a column.
 //! ```ignore
-//!     #[default(false)] // when using SQLite use 0 or 1
+//!     #[default(false)] // written as 0 or 1 on MySQL and SQLite
 //!     is_admin: bool,
 //!     #[default("")]
 //!     text: String,
 //!     #[default(0)]
 //!     number: i32,
+//!     #[default(gen_random_uuid())] // short for `sql = "gen_random_uuid()"`
+//!     id: uuid::Uuid,
+//!     #[default(sql = "CURRENT_TIMESTAMP")] // any SQL expression
+//!     created_at: DateTime<Utc>,
+//!     #[default(MAX_RETRIES)] // a Rust constant
+//!     retries: i32,
+//! ```
+//! SQL defaults are checked by the parser at compile time, constants are converted when migrations are generated.
+//!
+//! ### generated
+//! It declares a column computed from the other columns of the row.
+//! Generated columns are virtual unless `stored` is given, and PostgreSQL only supports stored columns:
+//! ```ignore
+//!     width: i32,
+//!     height: i32,
+//!     #[generated("width * height", stored)]
+//!     area: Option<i32>,
+//! ```
+//! This is equivalent to:
+//! ```sql
+//!     area INTEGER GENERATED ALWAYS AS (width * height) STORED,
+//! ```
+//! Generated columns cannot have a default, and their values are not copied when a table is rebuilt.
+//!
+//! ### nullable and not_null
+//! Columns are `NOT NULL` unless their field is an `Option`.
+//! `#[nullable]` and `#[not_null]` override the type of the field:
+//! ```ignore
+//!     #[nullable]
+//!     nickname: String,
+//!     #[not_null]
+//!     age: Option<i32>,
+//! ```
+//! When a column becomes `NOT NULL`, its null rows are first set to the default of the column,
+//! or to the zero value of its type, so the constraint can be added:
+//! ```sql
+//!     UPDATE profile SET nickname = '' WHERE nickname IS NULL;
+//!     ALTER TABLE profile ALTER COLUMN nickname SET NOT NULL;
 //! ```
 //!
 //! ### unique
@@ -180,14 +316,159 @@
 //! ```sql
 //!     UNIQUE (profile_id, post_id),
 //! ```
+//!
+//! ### index
+//! It is used to declare an index on one or more columns.
+//! ```ignore
+//!     #[index]
+//!     author: i32,
+//! ```
+//! Additional columns, a custom name and `unique` can be listed:
+//! ```ignore
+//!     #[index(published_at, name = "post_by_author", unique)]
+//!     author: i32,
+//!     published_at: i64,
+//! ```
+//! This is equivalent to:
+//! ```sql
+//!     CREATE UNIQUE INDEX post_by_author ON post (author, published_at);
+//! ```
+//! Composite indexes can also be declared on the structure:
+//! ```ignore
+//! #[derive(Model)]
+//! #[index(author, published_at)]
+//! struct Post {
+//!     author: i32,
+//!     published_at: i64,
+//! }
+//! ```
+//! Fields named `name` or `unique` are listed with `columns(...)`:
+//! ```ignore
+//! #[index(columns(name, unique), unique)]
+//! ```
+//!
+//! ### check
+//! It is used to declare a check constraint. The expression is validated at compile time:
+//! ```ignore
+//!     #[check("price > 0")]
+//!     price: i32,
+//! ```
+//! This is equivalent to:
+//! ```sql
+//!     CONSTRAINT product_check_price CHECK (price > 0),
+//! ```
+//! Checks that involve several columns can be declared on the structure, optionally with a custom name:
+//! ```ignore
+//! #[derive(Model)]
+//! #[check("price <= max_price", name = "price_below_max")]
+//! struct Product {
+//!     price: i32,
+//!     max_price: i32,
+//! }
+//! ```
+//!
+//! ### model
+//! It is used to declare additional options on a table or a column.
+//! When a field is renamed, `renamed_from` keeps its data by renaming the column instead of dropping it:
+//! ```ignore
+//!     #[model(renamed_from = "name")]
+//!     title: String,
+//! ```
+//! This is equivalent to:
+//! ```sql
+//!     ALTER TABLE post RENAME COLUMN name TO title;
+//! ```
+//! The same option can be used on the structure when it is renamed. Foreign keys that reference the table are kept:
+//! ```ignore
+//! #[derive(Model)]
+//! #[model(renamed_from = "profile")]
+//! struct Account {
+//!     #[primary_key]
+//!     id: i32,
+//! }
+//! ```
+//! This is equivalent to:
+//! ```sql
+//!     ALTER TABLE profile RENAME TO account;
+//! ```
+//! By default tables and columns are named after the lowercase name of the structure and its fields.
+//! `table` and `column` set a custom name, while `rename_all = "snake_case"` changes the naming policy of the structure:
+//! ```ignore
+//! #[derive(Model)]
+//! #[model(table = "likes", rename_all = "snake_case")]
+//! struct PostLike {
+//!     #[foreign_key(Post.id)]
+//!     postId: i32,
+//!     #[model(column = "kind")]
+//!     r#type: String,
+//! }
+//! ```
+//! This is equivalent to:
+//! ```sql
+//! CREATE TABLE likes (
+//!     post_id INTEGER NOT NULL,
+//!     kind TEXT NOT NULL,
+//!     CONSTRAINT likes_foreign_post_id_id FOREIGN KEY (post_id) REFERENCES post(id)
+//! );
+//! ```
+//! Foreign keys use the names declared by the referenced model.
+//!
+//! Fields that are not stored, such as computed or cached values, are excluded from the table with `skip`:
+//! ```ignore
+//! #[derive(Model)]
+//! struct Post {
+//!     title: String,
+//!     #[model(skip)]
+//!     word_count: usize,
+//! }
+//! ```
+//! Indexes, constraints and foreign keys that name a skipped field do not compile.
+//! The derive does not map rows, so the field also has to be skipped by the library that reads them,
+//! such as with `#[sqlx(skip)]` when deriving `sqlx::FromRow`.
+//! ## Enums
+//! Enums without fields can be stored as native SQL enums by deriving `SqlEnum`.
+//! The type and its values are named after the enum and its variants, following `rename_all` when it is set:
+//! ```ignore
+//! #[derive(SqlEnum)]
+//! #[model(rename_all = "snake_case")]
+//! enum Mood {
+//!     Sad,
+//!     Happy,
+//! }
+//!
+//! #[derive(Model)]
+//! struct Person {
+//!     mood: Mood,
+//! }
+//! ```
+//! This is equivalent to, on PostgreSQL:
+//! ```sql
+//! CREATE TYPE mood AS ENUM ('sad', 'happy');
+//! CREATE TABLE person (
+//!     mood mood NOT NULL
+//! );
+//! ```
+//! MySQL uses an inline `ENUM('sad', 'happy')` column, and SQLite a `TEXT` column with a `CHECK ("mood" IN ('sad', 'happy'))` constraint.
+//! New variants are added with `ALTER TYPE ... ADD VALUE`. PostgreSQL cannot remove values from a type,
+//! so when variants are removed or reordered the type is replaced by a new one and the columns that use it are converted.
 #![allow(unused_imports)]
-pub use sql_from_models_proc_macro::Model;
+pub use sql_from_models_proc_macro::{Model, SqlEnum};
+// the derives refer to `::sql_from_models`, which the tests of the crate use as well.
+#[cfg(test)]
+extern crate self as sql_from_models;
 
 #[macro_use]
 pub mod error;
 mod dialect;
+mod generator;
 mod prelude;
+mod settings;
 pub mod private;
+#[cfg(test)]
+mod tests;
 pub mod types;
 
+pub use dialect::Dialect;
+pub use generator::Generator;
+pub use private::{Migration, Model, Table};
 pub use types::*;
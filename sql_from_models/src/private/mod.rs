@@ -1,16 +1,54 @@
 //! This module is publicly accessible, but the interface can be subject to changes.
 //! This module is intended for macros only.
 //! Changes to elements in this module are not considered a breaking change. Do not depend directly on this module.
+//! `Model`, `Table` and `Migration` are the exception: they are re-exported at the root of the crate
+//! and are part of its public API.
 pub(crate) mod scheduler;
-use once_cell::sync::Lazy;
-pub(crate) use scheduler::driver::migration::Migration;
+pub use linkme;
+pub use scheduler::driver::migration::Migration;
 pub use scheduler::{
-    table::{constraint, Column, Table},
-    Scheduler,
+    entry,
+    table::{constraint, index, Column, DefaultLiteral, Table},
+    MODELS, PROTOCOL_VERSION,
 };
 
+/// a structure mapped to a table, implemented with `#[derive(Model)]`.
 pub trait Model {
+    /// the table the model is mapped to. Fields marked with `#[model(skip)]` are not part of it.
+    /// ```
+    /// use sql_from_models::{Dialect, Generator, Model};
+    /// #[derive(Model)]
+    /// struct Post {
+    ///     title: String,
+    ///     #[model(skip)]
+    ///     word_count: usize,
+    /// }
+    /// let generator = Generator::new(Dialect::PostgreSQL).format(None);
+    /// let migrations = generator.generate(vec![generator.target::<Post>()]).unwrap();
+    /// assert_eq!(
+    ///     migrations[0].up_sql(),
+    ///     "CREATE TABLE post (title TEXT NOT NULL);\n\n"
+    /// );
+    /// // outside of a generator, the dialect is read from `DATABASE_URL`, or is generic without it.
+    /// let table = Post::target();
+    /// ```
+    /// Skipped fields cannot be referenced by indexes, constraints or the foreign keys of other models:
+    /// ```compile_fail
+    /// use sql_from_models::Model;
+    /// #[derive(Model)]
+    /// struct Post {
+    ///     #[primary_key]
+    ///     id: i32,
+    ///     #[model(skip)]
+    ///     draft_id: i32,
+    /// }
+    /// #[derive(Model)]
+    /// struct Comment {
+    ///     #[foreign_key(Post.draft_id)]
+    ///     post_id: i32,
+    /// }
+    /// ```
     fn target() -> Table;
+    /// name of the table the model is mapped to.
+    fn table_name() -> &'static str;
 }
-
-pub static SCHEDULER: Lazy<Scheduler> = Lazy::new(Scheduler::new);
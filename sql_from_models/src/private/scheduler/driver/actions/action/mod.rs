@@ -12,13 +12,51 @@ pub(crate) enum ActionVariant<'table> {
 
     DropCol(Ident),
 
+    RenameCol {
+        old: Ident,
+        new: Ident,
+    },
+
+    AlterCol {
+        name: Ident,
+        op: AlterColumnOperation,
+    },
+
+    ModifyCol(&'table Column),
+
+    Backfill {
+        column: Ident,
+        value: Expr,
+    },
+
     CreateConstr(&'table TableConstraint),
 
     DropConstr(Ident),
 
+    CreateIndex(&'table CreateIndex),
+
+    DropIndex(Ident),
+
     TempMove(Move<'table>),
 
     CreateTable(&'table Table),
+
+    RenameTable(&'table ObjectName),
+
+    /// sets the comment of the table, or of one of its columns.
+    Comment {
+        column: Option<Ident>,
+        comment: Option<String>,
+    },
+
+    CreateType(&'table CreateType),
+
+    AlterType {
+        name: ObjectName,
+        operation: AlterTypeOperation,
+    },
+
+    DropType(ObjectName),
 }
 
 impl<'table> Action<'table> {
@@ -36,6 +74,110 @@ impl<'table> Action<'table> {
             variant: ActionVariant::CreateTable(target),
         }
     }
+    pub(super) fn rename_table(old: &'table Table, target: &'table Table) -> Self {
+        Self {
+            table_name: &old.name,
+            variant: ActionVariant::RenameTable(&target.name),
+        }
+    }
+    pub(super) fn comment_table(target: &'table Table) -> Self {
+        Self {
+            table_name: &target.name,
+            variant: ActionVariant::Comment {
+                column: None,
+                comment: target.comment.clone(),
+            },
+        }
+    }
+    pub(super) fn create_type(table_name: &'table ObjectName, ty: &'table CreateType) -> Self {
+        Self {
+            table_name,
+            variant: ActionVariant::CreateType(ty),
+        }
+    }
+
+    /// returns the actions that add the missing values of `target` to the type, in order.
+    pub(super) fn add_values(
+        table_name: &'table ObjectName,
+        current: &CreateType,
+        target: &CreateType,
+    ) -> Vec<Self> {
+        let labels = &target.labels;
+        labels
+            .iter()
+            .enumerate()
+            .filter(|(_, label)| !current.labels.contains(label))
+            .map(|(i, label)| {
+                let position = if i + 1 == labels.len() {
+                    None
+                } else if i > 0 {
+                    Some(EnumValuePosition::After(labels[i - 1].clone()))
+                } else {
+                    Some(EnumValuePosition::Before(labels[i + 1].clone()))
+                };
+                Self {
+                    table_name,
+                    variant: ActionVariant::AlterType {
+                        name: target.name.clone(),
+                        operation: AlterTypeOperation::AddValue {
+                            if_not_exists: false,
+                            value: label.clone(),
+                            position,
+                        },
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// values cannot be removed from an enum, so the type is replaced by a new one
+    /// and the columns that use it are converted.
+    pub(super) fn recreate_type(
+        table_name: &'table ObjectName,
+        target: &'table CreateType,
+        columns: Vec<(&'table ObjectName, &'table Column)>,
+    ) -> Vec<Self> {
+        let old_name = Ident::new(format!("{}_old", target.name));
+        let mut out = vec![
+            Self {
+                table_name,
+                variant: ActionVariant::AlterType {
+                    name: target.name.clone(),
+                    operation: AlterTypeOperation::RenameTo {
+                        new_name: old_name.clone(),
+                    },
+                },
+            },
+            Self::create_type(table_name, target),
+        ];
+        for (table_name, col) in columns {
+            let text = Expr::Cast(Cast {
+                expr: Box::new(Expr::Identifier(col.name.clone())),
+                data_type: as_text(&col.r#type),
+            });
+            let data_type = col.r#type.clone();
+            let using = Expr::Cast(Cast {
+                expr: Box::new(text),
+                data_type: data_type.clone(),
+            });
+            out.push(Self {
+                table_name,
+                variant: ActionVariant::AlterCol {
+                    name: col.name.clone(),
+                    op: AlterColumnOperation::SetDataType {
+                        data_type,
+                        using: Some(using),
+                    },
+                },
+            });
+        }
+        out.push(Self {
+            table_name,
+            variant: ActionVariant::DropType(ObjectName(vec![old_name])),
+        });
+        out
+    }
+
     pub(super) fn drop_cons(
         name: &'table ObjectName,
         cons: &'table TableConstraint,
@@ -52,22 +194,103 @@ impl<'table> Action<'table> {
             variant: ActionVariant::DropCol(col.name.clone()),
         }
     }
+    pub(super) fn rename_col(
+        name: &'table ObjectName,
+        old: &'table Column,
+        new: &'table Column,
+    ) -> Self {
+        Self {
+            table_name: name,
+            variant: ActionVariant::RenameCol {
+                old: old.name.clone(),
+                new: new.name.clone(),
+            },
+        }
+    }
+    /// returns the actions that alter `old` in place so it matches `new`.
+    pub(super) fn alter_col(
+        table_name: &'table ObjectName,
+        old: &'table Column,
+        new: &'table Column,
+    ) -> Vec<Self> {
+        // MySQL restates the whole definition of the column.
+        if matches!(dialect(), MySQL) {
+            return vec![Self {
+                table_name,
+                variant: ActionVariant::ModifyCol(new),
+            }];
+        }
+        let mut out: Vec<_> = old
+            .alter_ops(new)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|op| Self {
+                table_name,
+                variant: ActionVariant::AlterCol {
+                    name: new.name.clone(),
+                    op,
+                },
+            })
+            .collect();
+        if old.comment != new.comment {
+            out.push(Self {
+                table_name,
+                variant: ActionVariant::Comment {
+                    column: Some(new.name.clone()),
+                    comment: new.comment.clone(),
+                },
+            });
+        }
+        out
+    }
+    /// fills the null rows of a column that is about to become `NOT NULL`.
+    /// `column` is the name of the column at the time the rows are updated.
+    pub(super) fn backfill(
+        table_name: &'table ObjectName,
+        column: &Ident,
+        target: &'table Column,
+    ) -> Result<Self> {
+        Ok(Self {
+            table_name,
+            variant: ActionVariant::Backfill {
+                column: column.clone(),
+                value: target.backfill_value()?,
+            },
+        })
+    }
     pub(super) fn create_column(table_name: &'table ObjectName, col: &'table Column) -> Self {
         Self {
             table_name,
             variant: ActionVariant::CreateCol(col),
         }
     }
+    pub(super) fn create_index(name: &'table ObjectName, index: &'table CreateIndex) -> Self {
+        Self {
+            table_name: name,
+            variant: ActionVariant::CreateIndex(index),
+        }
+    }
+    pub(super) fn drop_index(name: &'table ObjectName, index: &'table CreateIndex) -> Self {
+        Self {
+            table_name: name,
+            variant: ActionVariant::DropIndex(index.ident()),
+        }
+    }
     pub(super) fn create_cons(name: &'table ObjectName, cons: &'table TableConstraint) -> Self {
         Self {
             table_name: name,
             variant: ActionVariant::CreateConstr(cons),
         }
     }
-    pub fn move_to(old: &'table Table, cols: &ColCRUD<'table>, cons: &ConsCRUD<'table>) -> Self {
-        let move_ = Move::new(old, cons, cols);
+    pub fn move_to(
+        target: &'table Table,
+        old: &'table Table,
+        cols: &ColCRUD<'table>,
+        cons: &ConsCRUD<'table>,
+    ) -> Self {
+        let move_ = Move::new(old, &target.comment, cons, cols);
         Self {
-            table_name: &old.name,
+            table_name: &target.name,
             variant: ActionVariant::TempMove(move_),
         }
     }
@@ -83,28 +306,113 @@ impl<'table> Action<'table> {
             CreateTable(table) => {
                 let statement = Statement::from(table.clone());
                 out.push(statement);
+                out.extend(comments(&table_name, &table.comment, &table.columns));
+                for index in &table.indexes {
+                    out.push(Statement::CreateIndex(index.clone()));
+                }
             }
-            other => {
-                let operation = match other {
-                    CreateCol(column) => AlterTableOperation::AddColumn {
+            CreateIndex(index) => {
+                let statement = Statement::CreateIndex(ast::CreateIndex {
+                    table_name,
+                    ..index.clone()
+                });
+                out.push(statement);
+            }
+            Backfill { column, value } => {
+                let statement = Statement::Update(Update {
+                    table_name,
+                    assignments: vec![Assignment {
+                        id: column.clone(),
+                        value,
+                    }],
+                    selection: Some(Expr::IsNull(Box::new(Expr::Identifier(column)))),
+                });
+                out.push(statement);
+            }
+            CreateCol(column) => {
+                let statement = Statement::AlterTable(AlterTable {
+                    name: table_name.clone(),
+                    operation: AlterTableOperation::AddColumn {
                         column_def: ColumnDef::from(column.clone()),
                     },
-
+                });
+                out.push(statement);
+                out.extend(comments(&table_name, &None, [column]));
+            }
+            // MySQL sets the comment of the table with `ALTER TABLE`,
+            // the comments of its columns are part of their definition.
+            Comment {
+                column: None,
+                comment,
+            } if matches!(dialect(), MySQL) => {
+                let statement = Statement::AlterTable(AlterTable {
+                    name: table_name,
+                    operation: AlterTableOperation::SetComment {
+                        comment: comment.unwrap_or_default(),
+                    },
+                });
+                out.push(statement);
+            }
+            Comment { column, comment } => out.push(comment_on(table_name, column, comment)),
+            CreateType(ty) => out.push(Statement::CreateType(ty.clone())),
+            AlterType { name, operation } => {
+                out.push(Statement::AlterType(ast::AlterType { name, operation }))
+            }
+            DropType(name) => {
+                let statement = Statement::Drop(Drop {
+                    object_type: ObjectType::Type,
+                    if_exists: false,
+                    names: vec![name],
+                    cascade: false,
+                    purge: false,
+                });
+                out.push(statement);
+            }
+            // MySQL indexes belong to their table.
+            DropIndex(name) if matches!(dialect(), MySQL) => {
+                let statement = Statement::AlterTable(AlterTable {
+                    name: table_name,
+                    operation: AlterTableOperation::DropIndex { name },
+                });
+                out.push(statement);
+            }
+            DropIndex(name) => {
+                let statement = Statement::Drop(Drop {
+                    object_type: ObjectType::Index,
+                    if_exists: false,
+                    names: vec![ObjectName(vec![name])],
+                    cascade: false,
+                    purge: false,
+                });
+                out.push(statement);
+            }
+            other => {
+                let operation = match other {
                     DropCol(column_name) => AlterTableOperation::DropColumn {
                         column_name,
                         if_exists: false,
-                        cascade: DIALECT.supports_cascade(),
+                        cascade: dialect().supports_cascade(),
+                    },
+                    RenameTable(new_name) => AlterTableOperation::RenameTable {
+                        table_name: new_name.clone(),
+                    },
+                    RenameCol { old, new } => AlterTableOperation::RenameColumn {
+                        old_column_name: old,
+                        new_column_name: new,
+                    },
+                    AlterCol { name, op } => AlterTableOperation::AlterColumn {
+                        column_name: name,
+                        op,
+                    },
+                    ModifyCol(column) => AlterTableOperation::ModifyColumn {
+                        column_def: ColumnDef::from(column.clone()),
                     },
                     DropConstr(name) => AlterTableOperation::DropConstraint {
                         name,
-                        cascade: DIALECT.supports_cascade(),
-                        restrict: false,
-                    },
-                    CreateConstr(constr) => AlterTableOperation::DropConstraint {
-                        name: Ident::new(constr.name().unwrap()),
-                        cascade: DIALECT.supports_cascade(),
+                        cascade: dialect().supports_cascade(),
                         restrict: false,
                     },
+                    CreateConstr(constr) => AlterTableOperation::AddConstraint(constr.clone()),
 
                     _ => todo!(),
                 };
@@ -124,7 +432,9 @@ pub fn depends(cons: &TableConstraint, tables: &[&Column]) -> bool {
     let names = match cons {
         TableConstraint::ForeignKey(fk) => &fk.columns,
         TableConstraint::Unique(unique) => &unique.columns,
-        _ => return false,
+        TableConstraint::Check(check) => {
+            return tables.iter().any(|col| references(&check.expr, &col.name))
+        }
     };
     let names = names.iter().map(ToString::to_string);
 
@@ -137,3 +447,66 @@ pub fn depends(cons: &TableConstraint, tables: &[&Column]) -> bool {
     }
     false
 }
+
+/// returns true if the expression mentions the column.
+fn references(expr: &Expr, column: &Ident) -> bool {
+    let sql = expr.to_string();
+    let tokens = tokenizer::Tokenizer::new(&dialect(), &sql)
+        .tokenize()
+        .unwrap_or_default();
+    tokens.iter().any(|token| {
+        matches!(token, tokenizer::Token::Word(word) if word.value.to_lowercase() == column.value.to_lowercase())
+    })
+}
+
+/// `COMMENT ON` statements for the comments of a table created on PostgreSQL.
+/// MySQL declares them along with the table.
+fn comments<'a>(
+    table_name: &ObjectName,
+    comment: &Option<String>,
+    columns: impl IntoIterator<Item = &'a Column>,
+) -> Vec<Statement> {
+    if !matches!(dialect(), PostgreSQL) {
+        return vec![];
+    }
+    let table = comment
+        .iter()
+        .map(|comment| comment_on(table_name.clone(), None, Some(comment.clone())));
+    let columns = columns.into_iter().filter_map(|col| {
+        let comment = col.comment.clone()?;
+        Some(comment_on(
+            table_name.clone(),
+            Some(col.name.clone()),
+            Some(comment),
+        ))
+    });
+    table.chain(columns).collect()
+}
+
+fn comment_on(
+    table_name: ObjectName,
+    column: Option<Ident>,
+    comment: Option<String>,
+) -> Statement {
+    let mut name = table_name;
+    let object_type = match column {
+        Some(column) => {
+            name.0.push(column);
+            CommentObject::Column
+        }
+        None => CommentObject::Table,
+    };
+    Statement::Comment(ast::Comment {
+        object_type,
+        name,
+        comment,
+    })
+}
+
+/// the text type with the same dimensions as an enum type or an array of it.
+fn as_text(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Array(data_type) => DataType::Array(Box::new(as_text(data_type))),
+        _ => DataType::Text,
+    }
+}
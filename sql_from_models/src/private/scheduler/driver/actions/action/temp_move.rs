@@ -5,15 +5,21 @@ pub(crate) struct Move<'table> {
     pub(super) new_cols: Vec<&'table Column>,
     pub(super) old_cols: Vec<&'table Column>,
     pub(super) constraints: Vec<&'table TableConstraint>,
+    pub(super) comment: &'table Option<String>,
 }
 
 impl<'table> Move<'table> {
-    pub fn new(old: &'table Table, cons: &ConsCRUD<'table>, cols: &ColCRUD<'table>) -> Self {
+    pub fn new(
+        old: &'table Table,
+        comment: &'table Option<String>,
+        cons: &ConsCRUD<'table>,
+        cols: &ColCRUD<'table>,
+    ) -> Self {
         let mut new_cols = vec![];
         let mut old_cols = vec![];
         let mut constraints = vec![];
         for col in &old.columns {
-            if !cols.to_delete(col) && !cols.to_update(col) {
+            if !cols.to_delete(col) && !cols.to_update(col) && !cols.to_rename(col) {
                 new_cols.push(col);
                 old_cols.push(col);
             }
@@ -22,6 +28,11 @@ impl<'table> Move<'table> {
             new_cols.push(col);
             old_cols.push(col);
         }
+        // renamed columns are copied from their old name.
+        for &(old_col, new_col) in &cols.rename {
+            new_cols.push(new_col);
+            old_cols.push(old_col);
+        }
         for con in &old.constraints {
             let to_delete = cons.to_delete(con);
             let to_update = cons.to_update(con);
@@ -30,12 +41,12 @@ impl<'table> Move<'table> {
             }
         }
         for con in &cons.update {
-            if !depends(con, &cols.create) || matches!(*DIALECT, SQLite) {
+            if !depends(con, &cols.create) || matches!(dialect(), SQLite) {
                 constraints.push(con);
             }
         }
         for con in &cons.create {
-            if !depends(con, &cols.create) || matches!(*DIALECT, SQLite) {
+            if !depends(con, &cols.create) || matches!(dialect(), SQLite) {
                 constraints.push(con);
             }
         }
@@ -43,6 +54,7 @@ impl<'table> Move<'table> {
             new_cols,
             old_cols,
             constraints,
+            comment,
         }
     }
 
@@ -51,11 +63,14 @@ impl<'table> Move<'table> {
         let create_table = self.create_table();
         let insert = self.insert_statement(table_name.clone())?;
         let drop = self.drop_statement(table_name.clone());
+        // comments are lost along with the old table.
+        let comments = comments(&table_name, self.comment, self.new_cols.iter().copied());
         let rename = self.rename(table_name);
         stmt.push(create_table);
         stmt.push(insert);
         stmt.push(drop);
         stmt.push(rename);
+        stmt.extend(comments);
         Ok(stmt)
     }
 
@@ -64,22 +79,25 @@ impl<'table> Move<'table> {
             name: ObjectName(vec![Ident::new("temp")]),
             columns: self.new_cols.iter().map(|&c| c.clone()).collect(),
             constraints: self.constraints.iter().map(|&c| c.clone()).collect(),
+            indexes: vec![],
+            types: vec![],
             if_not_exists: false,
             or_replace: false,
+            renamed_from: None,
+            comment: self.comment.clone(),
+            declared_on: vec![],
         }
         .into()
     }
     fn insert_statement(&self, table_name: ObjectName) -> Result<Statement> {
-        let new = self
+        // generated columns are computed by the database and cannot be copied.
+        let (new, old) = self
             .new_cols
             .iter()
-            .map(|&col| col.ident()) //
-            .collect();
-        let old = self
-            .old_cols
-            .iter()
-            .map(|&col| col.ident()) //
-            .collect();
+            .zip(&self.old_cols)
+            .filter(|(&new, _)| !new.is_generated())
+            .map(|(&new, &old)| (new.ident(), old.ident()))
+            .unzip();
 
         let insert = format!(
             "INSERT INTO temp ({}) SELECT {} FROM {};",
@@ -100,7 +118,7 @@ impl<'table> Move<'table> {
             object_type: ObjectType::Table,
             if_exists: false,
             names: vec![table_name],
-            cascade: !DIALECT.requires_move(),
+            cascade: !dialect().requires_move(),
             purge: false,
         })
     }
@@ -125,21 +143,3 @@ fn to_string<T: ToString>(collection: Vec<T>) -> String {
     }
     out
 }
-
-pub fn depends(cons: &TableConstraint, tables: &[&Column]) -> bool {
-    let names = match cons {
-        TableConstraint::ForeignKey(fk) => &fk.columns,
-        TableConstraint::Unique(unique) => &unique.columns,
-        _ => return false,
-    };
-    let names = names.iter().map(ToString::to_string);
-
-    for col in names {
-        for table_name in tables.iter().map(|t| t.name().unwrap()) {
-            if col.to_string() == table_name {
-                return true;
-            }
-        }
-    }
-    false
-}
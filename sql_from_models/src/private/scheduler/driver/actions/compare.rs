@@ -27,6 +27,11 @@ pub(crate) trait Compare: std::fmt::Debug {
     }
 
     fn ident(&self) -> Ident;
+
+    /// name the object had before being renamed, if any.
+    fn renamed_from(&self) -> Option<String> {
+        None
+    }
 }
 
 impl Compare for Column {
@@ -37,25 +42,20 @@ impl Compare for Column {
         Ok(self.name.to_string().to_lowercase())
     }
 
+    fn renamed_from(&self) -> Option<String> {
+        self.renamed_from
+            .as_ref()
+            .map(|name| name.to_string().to_lowercase())
+    }
+
     fn bodies_are_equal(&self, other: &Self) -> bool {
         let type1 = &self.r#type;
         let type2 = &other.r#type;
 
-        type1 == type2 && {
-            let h1 = self
-                .options
-                .iter()
-                .map(ToString::to_string)
-                .map(|string| string.to_lowercase())
-                .collect::<HashSet<_>>();
-            let h2 = other
-                .options
-                .iter()
-                .map(ToString::to_string)
-                .map(|string| string.to_lowercase())
-                .collect::<HashSet<_>>();
-            h1 == h2
-        }
+        type1 == type2
+            && self.normalized_default() == other.normalized_default()
+            && self.normalized_options() == other.normalized_options()
+            && (!dialect().supports_comments() || self.comment == other.comment)
     }
 }
 
@@ -105,43 +105,139 @@ impl Compare for TableConstraint {
             (ForeignKey(f0), ForeignKey(f1)) => {
                 f1.on_delete == f0.on_delete
                     && f1.on_update == f0.on_update
-                    && {
-                        let cols0 = f1
-                            .referred_columns
-                            .iter()
-                            .map(ToString::to_string)
-                            .map(|str| str.to_lowercase())
-                            .collect::<HashSet<_>>();
-                        let cols1 = f0
-                            .referred_columns
-                            .iter()
-                            .map(ToString::to_string)
-                            .map(|str| str.to_lowercase())
-                            .collect::<HashSet<_>>();
-                        cols0 == cols1
-                    }
+                    && f1.deferrable == f0.deferrable
                     && {
                         let name0 = f0.foreign_table.to_string().to_lowercase();
                         let name1 = f1.foreign_table.to_string().to_lowercase();
                         name0 == name1
                     }
-                    && {
-                        let cols0 = f0
-                            .columns
-                            .iter()
-                            .map(ToString::to_string)
-                            .map(|str| str.to_lowercase())
-                            .collect::<HashSet<_>>();
-                        let cols1 = f1
-                            .columns
-                            .iter()
-                            .map(ToString::to_string)
-                            .map(|str| str.to_lowercase())
-                            .collect::<HashSet<_>>();
-                        cols0 == cols1
-                    }
+                    // columns are compared in pairs, so swapping the columns
+                    // of a composite foreign key is a change.
+                    && column_pairs(f0) == column_pairs(f1)
             }
+            (Check(c0), Check(c1)) => normalize(&c0.expr) == normalize(&c1.expr),
             _ => false,
         }
     }
 }
+
+/// the (local, referred) column pairs of a foreign key.
+fn column_pairs(fk: &ast::ForeignKey) -> HashSet<(String, String)> {
+    fk.columns
+        .iter()
+        .zip(&fk.referred_columns)
+        .map(|(local, referred)| {
+            (
+                local.to_string().to_lowercase(),
+                referred.to_string().to_lowercase(),
+            )
+        })
+        .collect()
+}
+
+/// expressions are compared regardless of redundant parentheses, the quoting of identifiers,
+/// or the casing of unquoted identifiers and keywords. Literals are kept as they are.
+/// Parentheses are structural in the tree, so removing them does not change its meaning.
+pub(crate) fn normalize(expr: &Expr) -> Expr {
+    let boxed = |expr: &Expr| Box::new(normalize(expr));
+    match expr {
+        Expr::Nested(expr) => normalize(expr),
+        Expr::Identifier(ident) => Expr::Identifier(lowercase(ident)),
+        Expr::CompoundIdentifier(idents) => {
+            Expr::CompoundIdentifier(idents.iter().map(lowercase).collect())
+        }
+        Expr::IsNull(expr) => Expr::IsNull(boxed(expr)),
+        Expr::IsNotNull(expr) => Expr::IsNotNull(boxed(expr)),
+        Expr::InList(in_list) => Expr::InList(ast::InList {
+            expr: boxed(&in_list.expr),
+            list: in_list.list.iter().map(normalize).collect(),
+            negated: in_list.negated,
+        }),
+        Expr::Between(between) => Expr::Between(ast::Between {
+            expr: boxed(&between.expr),
+            negated: between.negated,
+            low: boxed(&between.low),
+            high: boxed(&between.high),
+        }),
+        Expr::BinaryOp(op) => Expr::BinaryOp(ast::BinaryOp {
+            left: boxed(&op.left),
+            op: op.op.clone(),
+            right: boxed(&op.right),
+        }),
+        Expr::UnaryOp(op) => Expr::UnaryOp(ast::UnaryOp {
+            op: op.op.clone(),
+            expr: boxed(&op.expr),
+        }),
+        Expr::Cast(cast) => Expr::Cast(ast::Cast {
+            expr: boxed(&cast.expr),
+            data_type: cast.data_type.clone(),
+        }),
+        Expr::TryCast(cast) => Expr::TryCast(ast::TryCast {
+            expr: boxed(&cast.expr),
+            data_type: cast.data_type.clone(),
+        }),
+        Expr::Collate(collate) => Expr::Collate(ast::Collate {
+            expr: boxed(&collate.expr),
+            collation: collate.collation.clone(),
+        }),
+        Expr::Function(function) => Expr::Function(ast::Function {
+            name: ObjectName(function.name.0.iter().map(lowercase).collect()),
+            args: function
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    FunctionArg::Named { name, arg } => FunctionArg::Named {
+                        name: lowercase(name),
+                        arg: normalize(arg),
+                    },
+                    FunctionArg::Unnamed(arg) => FunctionArg::Unnamed(normalize(arg)),
+                })
+                .collect(),
+            ..function.clone()
+        }),
+        Expr::Case(case) => Expr::Case(ast::Case {
+            operand: case.operand.as_deref().map(boxed),
+            conditions: case.conditions.iter().map(normalize).collect(),
+            results: case.results.iter().map(normalize).collect(),
+            else_result: case.else_result.as_deref().map(boxed),
+        }),
+        expr => expr.clone(),
+    }
+}
+
+/// unquoted identifiers are case insensitive. Once they are folded to lowercase,
+/// quoting makes no difference: `big` and `"big"` name the same column.
+fn lowercase(ident: &Ident) -> Ident {
+    match ident.quote_style {
+        None => Ident::new(ident.value.to_lowercase()),
+        Some(_) => Ident::new(ident.value.clone()),
+    }
+}
+
+impl Compare for CreateIndex {
+    fn ident(&self) -> Ident {
+        self.name.0.last().unwrap().clone()
+    }
+    fn name(&self) -> Result<String, Error> {
+        Ok(self.name.to_string().to_lowercase())
+    }
+
+    fn bodies_are_equal(&self, other: &Self) -> bool {
+        self.unique == other.unique && {
+            // the order of the columns matters for indexes.
+            let cols0 = self
+                .columns
+                .iter()
+                .map(ToString::to_string)
+                .map(|str| str.to_lowercase())
+                .collect::<Vec<_>>();
+            let cols1 = other
+                .columns
+                .iter()
+                .map(ToString::to_string)
+                .map(|str| str.to_lowercase())
+                .collect::<Vec<_>>();
+            cols0 == cols1
+        }
+    }
+}
@@ -5,11 +5,14 @@ pub(crate) struct CRUD<'table, T> {
     pub create: Vec<&'table T>,
     pub delete: Vec<&'table T>,
     pub update: Vec<&'table T>,
+    /// pairs of (current, target) objects whose name changed.
+    pub rename: Vec<(&'table T, &'table T)>,
     // pub keep: Vec<&'table T>,
 }
 
 pub(crate) type ColCRUD<'table> = CRUD<'table, Column>;
 pub(crate) type ConsCRUD<'table> = CRUD<'table, TableConstraint>;
+pub(crate) type IndexCRUD<'table> = CRUD<'table, CreateIndex>;
 
 impl<'table, T: Compare> CRUD<'table, T> {
     pub fn to_delete(&self, obj: &T) -> bool {
@@ -18,6 +21,9 @@ impl<'table, T: Compare> CRUD<'table, T> {
     pub fn to_update(&self, obj: &T) -> bool {
         self.update.iter().any(|&up| up.names_are_equal(&obj))
     }
+    pub fn to_rename(&self, obj: &T) -> bool {
+        self.rename.iter().any(|&(old, _)| old.names_are_equal(&obj))
+    }
     pub fn _to_create(&self, obj: &T) -> bool {
         self.create.iter().any(|&cr| cr.names_are_equal(&obj))
     }
@@ -28,6 +34,7 @@ impl<'table, T: Compare + PartialEq> CRUD<'table, T> {
         let mut update = vec![];
         let mut delete = vec![];
         let mut create = vec![];
+        let mut rename = vec![];
 
         for c1 in target {
             for c0 in current {
@@ -40,7 +47,11 @@ impl<'table, T: Compare + PartialEq> CRUD<'table, T> {
                 .iter()
                 .any(|c0| c0.are_equal(c1) || c0.are_modified(c1))
             {
-                create.push(c1);
+                if let Some(c0) = renamed(current, target, c1) {
+                    rename.push((c0, c1));
+                } else {
+                    create.push(c1);
+                }
             }
         }
 
@@ -48,6 +59,7 @@ impl<'table, T: Compare + PartialEq> CRUD<'table, T> {
             if target
                 .iter()
                 .all(|t| !c0.are_equal(t) && !c0.are_modified(t))
+                && !rename.iter().any(|&(old, _)| old.names_are_equal(c0))
             {
                 delete.push(c0);
             }
@@ -57,6 +69,22 @@ impl<'table, T: Compare + PartialEq> CRUD<'table, T> {
             create,
             update,
             delete,
+            rename,
         }
     }
 }
+
+/// finds the current object a target object was renamed from.
+/// The rename is ignored if the old name is still present in the target.
+fn renamed<'table, T: Compare>(current: &'table [T], target: &[T], obj: &T) -> Option<&'table T> {
+    let old_name = obj.renamed_from()?;
+    let is_kept = target
+        .iter()
+        .any(|t| t.name().ok().as_ref() == Some(&old_name));
+    if is_kept {
+        return None;
+    }
+    current
+        .iter()
+        .find(|c0| c0.name().ok().as_ref() == Some(&old_name))
+}
@@ -17,4 +17,10 @@ impl<'table> Inner<'table> {
         let target = &self.target.constraints;
         CRUD::new(current, target)
     }
+
+    pub fn indexes(&self) -> IndexCRUD<'table> {
+        let current = &self.table.unwrap().indexes;
+        let target = &self.target.indexes;
+        CRUD::new(current, target)
+    }
 }
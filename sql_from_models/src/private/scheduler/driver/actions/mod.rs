@@ -17,30 +17,73 @@ pub(crate) struct Actions<'table> {
 }
 impl<'table> Actions<'table> {
     pub fn new(schema: &'table Schema, target: &'table Table) -> Result<Self> {
-        let table = schema.get_table(&target.name);
+        for col in &target.columns {
+            col.check_dialect()?;
+        }
+        for constr in &target.constraints {
+            constraint::check_dialect(constr, target)?;
+        }
+        let table = schema.get_table(&target.name).or_else(|| {
+            let renamed_from = target.renamed_from.as_ref()?;
+            schema.get_table(renamed_from)
+        });
 
         let mut out = Self {
             name: &target.name,
             actions: vec![],
         };
+        out.init_types(schema, target);
         out.init(Inner { table, target })?;
         Ok(out)
     }
 
+    /// creates the enum types of the target, or updates their values.
+    fn init_types(&mut self, schema: &'table Schema, target: &'table Table) {
+        for ty in &target.types {
+            match schema.get_type(&ty.name) {
+                None => self.actions.push(Action::create_type(self.name, ty)),
+                Some(current) if current.labels == ty.labels => (),
+                Some(current) if is_subsequence(&current.labels, &ty.labels) => {
+                    let actions = Action::add_values(self.name, current, ty);
+                    self.actions.extend(actions);
+                }
+                Some(_) => {
+                    let columns = schema.columns_of_type(&ty.name);
+                    let actions = Action::recreate_type(self.name, ty, columns);
+                    self.actions.extend(actions);
+                }
+            }
+        }
+    }
+
     fn init(&mut self, inner: Inner<'table>) -> Result<()> {
         if inner.table.is_none() {
             let action = Action::create_table(inner.target);
             self.actions.push(action);
             return Ok(());
         }
+        let table = inner.table.unwrap();
+        if table.name != inner.target.name {
+            let action = Action::rename_table(table, inner.target);
+            self.actions.push(action);
+        }
         let columns = inner.columns();
         let constraints = inner.constraints();
+        let indexes = inner.indexes();
 
-        if move_required(&columns, &constraints) {
+        if move_required(table, &columns, &constraints) {
             self.perform_move(&inner, columns, constraints)?;
         } else {
             let table_name = &inner.target.name;
-            for col in columns.delete {
+            if table.comment != inner.target.comment && dialect().supports_comments() {
+                self.actions.push(Action::comment_table(inner.target));
+            }
+            // indexes are dropped first, as they may depend on dropped columns.
+            for index in indexes.delete.iter().chain(&indexes.update) {
+                let action = Action::drop_index(table_name, index);
+                self.actions.push(action);
+            }
+            for &col in &columns.delete {
                 let action = Action::drop_col(table_name, col);
                 self.actions.push(action);
             }
@@ -52,6 +95,19 @@ impl<'table> Actions<'table> {
                 let action = Action::drop_cons(table_name, cons)?;
                 self.actions.push(action);
             }
+            for &(old, new) in &columns.rename {
+                let action = Action::rename_col(table_name, old, new);
+                self.actions.push(action);
+            }
+            for (old, new) in altered_columns(table, &columns) {
+                if becomes_not_null(old, new) {
+                    self.actions.push(Action::backfill(table_name, &new.name, new)?);
+                }
+            }
+            for (old, new) in altered_columns(table, &columns) {
+                let actions = Action::alter_col(table_name, old, new);
+                self.actions.extend(actions);
+            }
 
             for col in columns.create {
                 let action = Action::create_column(table_name, col);
@@ -66,6 +122,10 @@ impl<'table> Actions<'table> {
                 let action = Action::create_cons(table_name, cons);
                 self.actions.push(action);
             }
+            for index in indexes.create.iter().chain(&indexes.update) {
+                let action = Action::create_index(table_name, index);
+                self.actions.push(action);
+            }
         }
         Ok(())
     }
@@ -76,16 +136,22 @@ impl<'table> Actions<'table> {
         cols: ColCRUD<'table>,
         cons: ConsCRUD<'table>,
     ) -> Result<()> {
+        let table_name = &inner.target.name;
+        // null rows would not fit in the new table.
+        for (old, new) in altered_columns(inner.table.unwrap(), &cols) {
+            if becomes_not_null(old, new) {
+                self.actions.push(Action::backfill(table_name, &old.name, new)?);
+            }
+        }
         // constraints are dropped so they do not conflict
-        if matches!(*DIALECT, PostgreSQL | MySQL) {
+        if matches!(dialect(), PostgreSQL | MySQL) {
             for con in &inner.table.unwrap().constraints {
-                let drop_cons = Action::drop_cons(&inner.table.unwrap().name, con)?;
+                let drop_cons = Action::drop_cons(table_name, con)?;
                 self.actions.push(drop_cons);
             }
         }
-        let move_action = Action::move_to(inner.table.unwrap(), &cols, &cons);
+        let move_action = Action::move_to(inner.target, inner.table.unwrap(), &cols, &cons);
         self.actions.push(move_action);
-        let table_name = &inner.target.name;
 
         // moves do not create columns as their names may conflict with constraints.
         for &col in &cols.create {
@@ -98,11 +164,16 @@ impl<'table> Actions<'table> {
         // SQLite does not enforce constraints so these are all created
         // in the move step
         for &cons in &cons.create {
-            if depends(cons, &cols.create) && !matches!(*DIALECT, SQLite) {
+            if depends(cons, &cols.create) && !matches!(dialect(), SQLite) {
                 let action = Action::create_cons(table_name, cons);
                 self.actions.push(action);
             }
         }
+        // indexes are dropped along with the old table.
+        for index in &inner.target.indexes {
+            let action = Action::create_index(table_name, index);
+            self.actions.push(action);
+        }
         Ok(())
     }
 
@@ -121,12 +192,51 @@ impl<'table> Actions<'table> {
         Ok(migrations)
     }
 }
-pub(crate) fn move_required<'table>(cols: &ColCRUD<'table>, cons: &ConsCRUD<'table>) -> bool {
-    let sqlite_conditions = DIALECT.requires_move()
+pub(crate) fn move_required<'table>(
+    table: &'table Table,
+    cols: &ColCRUD<'table>,
+    cons: &ConsCRUD<'table>,
+) -> bool {
+    let sqlite_conditions = dialect().requires_move()
         && !(cols.update.is_empty()
             && cols.delete.is_empty()
             && cons.delete.is_empty()
             && cons.create.is_empty()
             && cons.update.is_empty());
-    sqlite_conditions || !cols.update.is_empty()
+    // columns that cannot be altered in place have to be moved.
+    let cannot_alter = altered_columns(table, cols)
+        .iter()
+        .any(|(old, new)| !dialect().alters_columns() || old.alter_ops(new).is_none());
+    sqlite_conditions || cannot_alter
+}
+
+fn becomes_not_null(old: &Column, new: &Column) -> bool {
+    old.is_nullable() && !new.is_nullable()
+}
+
+/// returns true if the values of `current` appear in `target` in the same order.
+fn is_subsequence(current: &[String], target: &[String]) -> bool {
+    let mut target = target.iter();
+    current.iter().all(|label| target.any(|t| t == label))
+}
+
+/// returns pairs of (current, target) columns whose definition changed,
+/// including renamed columns.
+fn altered_columns<'table>(
+    table: &'table Table,
+    cols: &ColCRUD<'table>,
+) -> Vec<(&'table Column, &'table Column)> {
+    let updated = cols.update.iter().filter_map(|&new| {
+        table
+            .columns
+            .iter()
+            .find(|old| old.names_are_equal(new))
+            .map(|old| (old, new))
+    });
+    let renamed = cols
+        .rename
+        .iter()
+        .copied()
+        .filter(|(old, new)| !old.bodies_are_equal(new));
+    updated.chain(renamed).collect()
 }
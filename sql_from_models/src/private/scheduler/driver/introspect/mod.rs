@@ -0,0 +1,97 @@
+//! Reads the schema of a live database so migrations can be generated
+//! against its real state instead of the state described by the migration files.
+//! Each backend reads the catalog into the tables and types of the `Schema`.
+use crate::prelude::*;
+use url::Url;
+#[cfg(feature = "sqlx-mysql")]
+pub(crate) mod mysql;
+#[cfg(feature = "sqlx-postgres")]
+pub(crate) mod postgres;
+#[cfg(feature = "sqlx-sqlite")]
+pub(crate) mod sqlite;
+
+const FEATURES: [&str; 3] = ["sqlx-postgres", "sqlx-mysql", "sqlx-sqlite"];
+
+/// tables and enum types of a database.
+#[derive(Default)]
+pub(crate) struct Catalog {
+    pub tables: Vec<Table>,
+    pub types: Vec<CreateType>,
+}
+
+/// reads the tables and types of the database at `url`.
+pub(crate) fn catalog(url: &str) -> Result<Catalog> {
+    let parsed = Url::parse(url).map_err(|err| Error::Database(err.to_string()))?;
+    match Dialect::from_url(&parsed) {
+        #[cfg(feature = "sqlx-postgres")]
+        PostgreSQL => block_on(postgres::catalog(url)),
+        #[cfg(feature = "sqlx-mysql")]
+        MySQL => block_on(mysql::catalog(url)),
+        #[cfg(feature = "sqlx-sqlite")]
+        SQLite => block_on(sqlite::catalog(url)),
+        dialect => Err(Error::Database(format!(
+            "{:?} databases are not supported. help: enable one of the features {:?}.",
+            dialect, FEATURES
+        ))),
+    }
+}
+
+/// runs the queries on a runtime of its own, as the schema is read from synchronous code.
+/// sqlx can run on it with either the tokio or the async-std runtime.
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+fn block_on(future: impl future::Future<Output = Result<Catalog>>) -> Result<Catalog> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|err| Error::Database(err.to_string()))?
+        .block_on(future)
+}
+
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+impl From<sqlx::Error> for Error {
+    fn from(err: sqlx::Error) -> Error {
+        Error::Database(err.to_string())
+    }
+}
+
+/// names that are not plain lowercase identifiers are quoted with the quote of the dialect.
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+pub(crate) fn ident(name: &str, quote: char) -> Ident {
+    let mut chars = name.chars();
+    let is_plain = matches!(chars.next(), Some('a'..='z' | '_'))
+        && chars.all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_'));
+    if is_plain {
+        Ident::new(name)
+    } else {
+        Ident::with_quote(quote, name)
+    }
+}
+
+/// parses a fragment of SQL read from the catalog, such as a data type or an expression.
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+pub(crate) fn parse<T>(
+    dialect: &dyn dialect::Dialect,
+    sql: &str,
+    parse: impl FnOnce(&mut parser::Parser) -> Result<T, parser::ParserError>,
+) -> Result<T> {
+    let tokens = tokenizer::Tokenizer::new(dialect, sql)
+        .tokenize()
+        .map_err(parser::ParserError::from)?;
+    Ok(parse(&mut parser::Parser::new(tokens, dialect))?)
+}
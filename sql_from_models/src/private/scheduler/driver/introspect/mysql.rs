@@ -0,0 +1,294 @@
+use super::*;
+use dialect::MySqlDialect;
+use sqlx::{mysql::MySqlRow, Connection, MySqlConnection, Row};
+use tokenizer::Token;
+
+const TABLES: &str = "
+SELECT CAST(TABLE_NAME AS CHAR), CAST(TABLE_COMMENT AS CHAR)
+FROM information_schema.TABLES
+WHERE TABLE_SCHEMA = DATABASE()
+  AND TABLE_TYPE = 'BASE TABLE'
+  AND TABLE_NAME <> '_sqlx_migrations'
+ORDER BY TABLE_NAME";
+
+const COLUMNS: &str = "
+SELECT CAST(COLUMN_NAME AS CHAR), CAST(COLUMN_TYPE AS CHAR), CAST(IS_NULLABLE AS CHAR),
+       CAST(COLUMN_DEFAULT AS CHAR), CAST(EXTRA AS CHAR), CAST(GENERATION_EXPRESSION AS CHAR),
+       CAST(COLUMN_COMMENT AS CHAR)
+FROM information_schema.COLUMNS
+WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?
+ORDER BY ORDINAL_POSITION";
+
+const CONSTRAINTS: &str = "
+SELECT CAST(tc.CONSTRAINT_NAME AS CHAR), CAST(tc.CONSTRAINT_TYPE AS CHAR),
+       CAST(GROUP_CONCAT(k.COLUMN_NAME ORDER BY k.ORDINAL_POSITION) AS CHAR),
+       CAST(MAX(k.REFERENCED_TABLE_NAME) AS CHAR),
+       CAST(GROUP_CONCAT(k.REFERENCED_COLUMN_NAME ORDER BY k.ORDINAL_POSITION) AS CHAR),
+       CAST(MAX(rc.DELETE_RULE) AS CHAR),
+       CAST(MAX(rc.UPDATE_RULE) AS CHAR)
+FROM information_schema.TABLE_CONSTRAINTS tc
+JOIN information_schema.KEY_COLUMN_USAGE k
+  ON k.CONSTRAINT_SCHEMA = tc.CONSTRAINT_SCHEMA
+  AND k.TABLE_NAME = tc.TABLE_NAME
+  AND k.CONSTRAINT_NAME = tc.CONSTRAINT_NAME
+LEFT JOIN information_schema.REFERENTIAL_CONSTRAINTS rc
+  ON rc.CONSTRAINT_SCHEMA = tc.CONSTRAINT_SCHEMA
+  AND rc.TABLE_NAME = tc.TABLE_NAME
+  AND rc.CONSTRAINT_NAME = tc.CONSTRAINT_NAME
+WHERE tc.TABLE_SCHEMA = DATABASE()
+  AND tc.TABLE_NAME = ?
+  AND tc.CONSTRAINT_TYPE IN ('PRIMARY KEY', 'UNIQUE', 'FOREIGN KEY')
+GROUP BY tc.CONSTRAINT_NAME, tc.CONSTRAINT_TYPE
+ORDER BY tc.CONSTRAINT_NAME";
+
+const CHECKS: &str = "
+SELECT CAST(cc.CONSTRAINT_NAME AS CHAR), CAST(cc.CHECK_CLAUSE AS CHAR)
+FROM information_schema.CHECK_CONSTRAINTS cc
+JOIN information_schema.TABLE_CONSTRAINTS tc
+  ON tc.CONSTRAINT_SCHEMA = cc.CONSTRAINT_SCHEMA
+  AND tc.CONSTRAINT_NAME = cc.CONSTRAINT_NAME
+WHERE tc.TABLE_SCHEMA = DATABASE()
+  AND tc.TABLE_NAME = ?
+  AND tc.CONSTRAINT_TYPE = 'CHECK'
+ORDER BY cc.CONSTRAINT_NAME";
+
+// indexes on expressions are not listed.
+const INDEXES: &str = "
+SELECT CAST(INDEX_NAME AS CHAR), CAST(MAX(NON_UNIQUE) AS SIGNED),
+       CAST(GROUP_CONCAT(COLUMN_NAME ORDER BY SEQ_IN_INDEX) AS CHAR)
+FROM information_schema.STATISTICS
+WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?
+GROUP BY INDEX_NAME
+HAVING COUNT(COLUMN_NAME) = COUNT(*)
+ORDER BY INDEX_NAME";
+
+const QUOTE: char = '`';
+
+pub(super) async fn catalog(url: &str) -> Result<Catalog> {
+    let mut conn = MySqlConnection::connect(url).await?;
+    let mut out = Catalog::default();
+    for row in sqlx::query(TABLES).fetch_all(&mut conn).await? {
+        let name: String = row.try_get(0)?;
+        let comment: Option<String> = row.try_get(1)?;
+        let mut table = Table::new(&name);
+        table.name = ObjectName(vec![ident(&name, QUOTE)]);
+        table.comment = comment.filter(|comment| !comment.is_empty());
+        for row in sqlx::query(COLUMNS)
+            .bind(&name)
+            .fetch_all(&mut conn)
+            .await?
+        {
+            table.columns.push(column(
+                row.try_get(0)?,
+                row.try_get(1)?,
+                row.try_get(2)?,
+                row.try_get(3)?,
+                row.try_get(4)?,
+                row.try_get(5)?,
+                row.try_get(6)?,
+            )?);
+        }
+        // indexes created for constraints are part of the constraint.
+        let mut constraint_names = vec![];
+        for row in sqlx::query(CONSTRAINTS)
+            .bind(&name)
+            .fetch_all(&mut conn)
+            .await?
+        {
+            let constraint_name: String = row.try_get(0)?;
+            table.constraints.push(constraint(&name, &row)?);
+            constraint_names.push(constraint_name);
+        }
+        // older servers do not have the `CHECK_CONSTRAINTS` table.
+        let checks = sqlx::query(CHECKS).bind(&name).fetch_all(&mut conn).await;
+        for row in checks.unwrap_or_default() {
+            let check: String = row.try_get(0)?;
+            let clause: String = row.try_get(1)?;
+            table.constraints.push(TableConstraint::Check(ast::Check {
+                name: Some(ident(&check, QUOTE)),
+                expr: Box::new(parse(&MySqlDialect {}, &clause, |p| p.parse_expr())?),
+            }));
+        }
+        for row in sqlx::query(INDEXES)
+            .bind(&name)
+            .fetch_all(&mut conn)
+            .await?
+        {
+            let index: String = row.try_get(0)?;
+            let non_unique: i64 = row.try_get(1)?;
+            let columns: String = row.try_get(2)?;
+            if index == "PRIMARY" || constraint_names.contains(&index) {
+                continue;
+            }
+            table.indexes.push(CreateIndex {
+                name: ObjectName(vec![ident(&index, QUOTE)]),
+                table_name: table.name.clone(),
+                columns: idents(&columns)
+                    .into_iter()
+                    .map(|column| OrderByExpr {
+                        expr: Expr::Identifier(column),
+                        asc: None,
+                        nulls_first: None,
+                    })
+                    .collect(),
+                unique: non_unique == 0,
+                if_not_exists: false,
+            });
+        }
+        out.tables.push(table);
+    }
+    conn.close().await?;
+    Ok(out)
+}
+
+fn constraint(table: &str, row: &MySqlRow) -> Result<TableConstraint> {
+    let name: String = row.try_get(0)?;
+    let kind: String = row.try_get(1)?;
+    let columns: String = row.try_get(2)?;
+    Ok(match &*kind {
+        // MySQL always names primary keys `PRIMARY`,
+        // so they are given the name used by the derive macro.
+        "PRIMARY KEY" => TableConstraint::Unique(ast::Unique {
+            name: Some(ident(
+                &format!("{}_primary_{}", table, columns.replace(',', "_")),
+                QUOTE,
+            )),
+            columns: idents(&columns),
+            is_primary: true,
+        }),
+        "UNIQUE" => TableConstraint::Unique(ast::Unique {
+            name: Some(ident(&name, QUOTE)),
+            columns: idents(&columns),
+            is_primary: false,
+        }),
+        _ => {
+            let foreign_table: String = row.try_get(3)?;
+            let referred_columns: String = row.try_get(4)?;
+            TableConstraint::ForeignKey(ast::ForeignKey {
+                name: Some(ident(&name, QUOTE)),
+                columns: idents(&columns),
+                foreign_table: ObjectName(vec![ident(&foreign_table, QUOTE)]),
+                referred_columns: idents(&referred_columns),
+                on_delete: referential_action(&row.try_get::<String, _>(5)?),
+                on_update: referential_action(&row.try_get::<String, _>(6)?),
+                deferrable: None,
+            })
+        }
+    })
+}
+
+/// columns listed by `GROUP_CONCAT`.
+fn idents(columns: &str) -> Vec<Ident> {
+    columns
+        .split(',')
+        .map(|column| ident(column, QUOTE))
+        .collect()
+}
+
+/// `NO ACTION` is the default, so it is left out like in the migrations.
+fn referential_action(rule: &str) -> Option<ReferentialAction> {
+    match rule {
+        "RESTRICT" => Some(ReferentialAction::Restrict),
+        "CASCADE" => Some(ReferentialAction::Cascade),
+        "SET NULL" => Some(ReferentialAction::SetNull),
+        "SET DEFAULT" => Some(ReferentialAction::SetDefault),
+        _ => None,
+    }
+}
+
+pub(crate) fn column(
+    name: String,
+    r#type: String,
+    is_nullable: String,
+    default: Option<String>,
+    extra: String,
+    generation_expression: Option<String>,
+    comment: Option<String>,
+) -> Result<Column> {
+    let dialect = MySqlDialect {};
+    let r#type = data_type(&r#type);
+    let mut options = vec![];
+    if is_nullable == "NO" {
+        options.push(ColumnOption::NotNull);
+    }
+    if extra.contains("auto_increment") {
+        options.push(ColumnOption::DialectSpecific(vec![Token::make_keyword(
+            "AUTO_INCREMENT",
+        )]));
+    }
+    // generated columns are marked `STORED GENERATED` or `VIRTUAL GENERATED` in `EXTRA`.
+    let stored = extra.contains("STORED GENERATED");
+    if stored || extra.contains("VIRTUAL GENERATED") {
+        let expr = generation_expression.unwrap_or_default().replace('`', "");
+        options.push(ColumnOption::Generated {
+            expr: parse(&dialect, &expr, |p| p.parse_expr())?,
+            stored,
+        });
+    }
+    if let Some(default) = default {
+        options.push(ColumnOption::Default(default_expr(
+            &default, &extra, &r#type,
+        )?));
+    }
+    Ok(Column {
+        name: ident(&name, QUOTE),
+        r#type: parse(&dialect, &r#type, |p| p.parse_data_type())?,
+        options: options
+            .into_iter()
+            .map(|option| ColumnOptionDef { name: None, option })
+            .collect(),
+        renamed_from: None,
+        comment: comment.filter(|comment| !comment.is_empty()),
+    })
+}
+
+/// `COLUMN_DEFAULT` holds literals without their quotes. Expressions are marked
+/// `DEFAULT_GENERATED` in `EXTRA`, except `CURRENT_TIMESTAMP` before MySQL 8.0.13.
+/// `type` is the type of the column returned by `data_type`.
+pub(crate) fn default_expr(default: &str, extra: &str, r#type: &str) -> Result<Expr> {
+    let starts_with = |prefixes: &[&str]| prefixes.iter().any(|p| r#type.starts_with(p));
+    let is_temporal = starts_with(&["timestamp", "datetime", "date", "time"]);
+    let is_numeric = starts_with(&[
+        "tinyint",
+        "smallint",
+        "mediumint",
+        "int",
+        "bigint",
+        "decimal",
+        "numeric",
+        "float",
+        "double",
+        "real",
+        "BOOLEAN",
+    ]);
+    let is_expression = extra.contains("DEFAULT_GENERATED")
+        || (is_temporal && default.to_uppercase().starts_with("CURRENT_TIMESTAMP"))
+        || (is_numeric && default.parse::<f64>().is_ok());
+    if is_expression {
+        parse(&MySqlDialect {}, default, |p| p.parse_expr())
+    } else {
+        Ok(Expr::Value(Value::SingleQuotedString(default.into())))
+    }
+}
+
+/// removes the display width of integers, which does not change the type.
+/// `TINYINT(1)` is how MySQL stores booleans.
+pub(crate) fn data_type(r#type: &str) -> String {
+    let r#type = r#type.to_lowercase();
+    if r#type.starts_with("tinyint(1)") {
+        return "BOOLEAN".into();
+    }
+    let integers = ["tinyint(", "smallint(", "mediumint(", "int(", "bigint("];
+    let r#type = match (
+        integers.iter().any(|int| r#type.starts_with(int)),
+        r#type.find(')'),
+    ) {
+        (true, Some(end)) => format!(
+            "{}{}",
+            &r#type[..r#type.find('(').unwrap()],
+            &r#type[end + 1..]
+        ),
+        _ => r#type,
+    };
+    r#type.replace(" zerofill", "")
+}
@@ -0,0 +1,259 @@
+use super::*;
+use dialect::PostgreSqlDialect;
+use sqlx::{postgres::PgRow, Connection, PgConnection, Row};
+
+const TABLES: &str = "
+SELECT c.relname::text, obj_description(c.oid, 'pg_class')
+FROM pg_class c
+JOIN pg_namespace n ON n.oid = c.relnamespace
+WHERE c.relkind IN ('r', 'p')
+  AND n.nspname = current_schema()
+  AND c.relname <> '_sqlx_migrations'
+ORDER BY c.relname";
+
+const ENUMS: &str = "
+SELECT t.typname::text, array_agg(e.enumlabel::text ORDER BY e.enumsortorder)
+FROM pg_type t
+JOIN pg_enum e ON e.enumtypid = t.oid
+JOIN pg_namespace n ON n.oid = t.typnamespace
+WHERE n.nspname = current_schema()
+GROUP BY t.typname
+ORDER BY t.typname";
+
+const COLUMNS: &str = "
+SELECT a.attname::text, format_type(a.atttypid, a.atttypmod), a.attnotnull, pg_get_expr(d.adbin, d.adrelid),
+       a.attgenerated = 's', col_description(a.attrelid, a.attnum)
+FROM pg_attribute a
+JOIN pg_class c ON c.oid = a.attrelid
+JOIN pg_namespace n ON n.oid = c.relnamespace
+LEFT JOIN pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+WHERE c.relname = $1
+  AND n.nspname = current_schema()
+  AND a.attnum > 0
+  AND NOT a.attisdropped
+ORDER BY a.attnum";
+
+// the columns of the constraint are listed in the order of its definition.
+const CONSTRAINTS: &str = "
+SELECT con.conname::text, con.contype::text,
+       ARRAY(
+         SELECT a.attname::text
+         FROM unnest(con.conkey) WITH ORDINALITY AS k(attnum, n)
+         JOIN pg_attribute a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+         ORDER BY k.n
+       ),
+       f.relname::text,
+       ARRAY(
+         SELECT a.attname::text
+         FROM unnest(con.confkey) WITH ORDINALITY AS k(attnum, n)
+         JOIN pg_attribute a ON a.attrelid = con.confrelid AND a.attnum = k.attnum
+         ORDER BY k.n
+       ),
+       con.confdeltype::text, con.confupdtype::text, con.condeferrable, con.condeferred,
+       pg_get_expr(con.conbin, con.conrelid)
+FROM pg_constraint con
+JOIN pg_class c ON c.oid = con.conrelid
+JOIN pg_namespace n ON n.oid = c.relnamespace
+LEFT JOIN pg_class f ON f.oid = con.confrelid
+WHERE c.relname = $1
+  AND n.nspname = current_schema()
+  AND con.contype IN ('p', 'u', 'f', 'c')
+ORDER BY con.conname";
+
+// indexes backing constraints, on expressions or partial indexes are not listed.
+const INDEXES: &str = "
+SELECT i.relname::text, ix.indisunique, array_agg(a.attname::text ORDER BY k.n)
+FROM pg_index ix
+JOIN pg_class t ON t.oid = ix.indrelid
+JOIN pg_namespace n ON n.oid = t.relnamespace
+JOIN pg_class i ON i.oid = ix.indexrelid
+JOIN LATERAL unnest(ix.indkey) WITH ORDINALITY AS k(attnum, n) ON true
+JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+WHERE t.relname = $1
+  AND n.nspname = current_schema()
+  AND 0 <> ALL (ix.indkey)
+  AND ix.indpred IS NULL
+  AND NOT EXISTS (
+    SELECT 1 FROM pg_constraint con
+    WHERE con.conindid = ix.indexrelid AND con.contype IN ('p', 'u', 'x')
+  )
+GROUP BY i.relname, ix.indisunique
+ORDER BY i.relname";
+
+const QUOTE: char = '"';
+
+pub(super) async fn catalog(url: &str) -> Result<Catalog> {
+    let mut conn = PgConnection::connect(url).await?;
+    let mut out = Catalog::default();
+    // types are created first, as the columns depend on them.
+    for row in sqlx::query(ENUMS).fetch_all(&mut conn).await? {
+        let name: String = row.try_get(0)?;
+        out.types.push(CreateType {
+            name: ObjectName(vec![ident(&name, QUOTE)]),
+            labels: row.try_get(1)?,
+        });
+    }
+    for row in sqlx::query(TABLES).fetch_all(&mut conn).await? {
+        let name: String = row.try_get(0)?;
+        let mut table = Table::new(&name);
+        table.name = ObjectName(vec![ident(&name, QUOTE)]);
+        table.comment = row.try_get(1)?;
+        for row in sqlx::query(COLUMNS)
+            .bind(&name)
+            .fetch_all(&mut conn)
+            .await?
+        {
+            let r#type: String = row.try_get(1)?;
+            let default: Option<String> = row.try_get(3)?;
+            table.columns.push(column(
+                row.try_get(0)?,
+                &r#type,
+                row.try_get(2)?,
+                default.as_deref(),
+                row.try_get(4)?,
+                row.try_get(5)?,
+            )?);
+        }
+        for row in sqlx::query(CONSTRAINTS)
+            .bind(&name)
+            .fetch_all(&mut conn)
+            .await?
+        {
+            table.constraints.push(constraint(&row)?);
+        }
+        for row in sqlx::query(INDEXES)
+            .bind(&name)
+            .fetch_all(&mut conn)
+            .await?
+        {
+            let index: String = row.try_get(0)?;
+            let columns: Vec<String> = row.try_get(2)?;
+            table.indexes.push(CreateIndex {
+                name: ObjectName(vec![ident(&index, QUOTE)]),
+                table_name: table.name.clone(),
+                columns: columns
+                    .iter()
+                    .map(|column| OrderByExpr {
+                        expr: Expr::Identifier(ident(column, QUOTE)),
+                        asc: None,
+                        nulls_first: None,
+                    })
+                    .collect(),
+                unique: row.try_get(1)?,
+                if_not_exists: false,
+            });
+        }
+        out.tables.push(table);
+    }
+    conn.close().await?;
+    Ok(out)
+}
+
+fn constraint(row: &PgRow) -> Result<TableConstraint> {
+    let name: String = row.try_get(0)?;
+    let name = Some(ident(&name, QUOTE));
+    let kind: String = row.try_get(1)?;
+    let columns: Vec<String> = row.try_get(2)?;
+    let columns = columns.iter().map(|column| ident(column, QUOTE)).collect();
+    Ok(match &*kind {
+        "p" | "u" => TableConstraint::Unique(ast::Unique {
+            name,
+            columns,
+            is_primary: kind == "p",
+        }),
+        "f" => {
+            let foreign_table: String = row.try_get(3)?;
+            let referred_columns: Vec<String> = row.try_get(4)?;
+            let deferrable = match (row.try_get(7)?, row.try_get(8)?) {
+                (false, _) => None,
+                (true, false) => Some(Deferrable::InitiallyImmediate),
+                (true, true) => Some(Deferrable::InitiallyDeferred),
+            };
+            TableConstraint::ForeignKey(ast::ForeignKey {
+                name,
+                columns,
+                foreign_table: ObjectName(vec![ident(&foreign_table, QUOTE)]),
+                referred_columns: referred_columns
+                    .iter()
+                    .map(|column| ident(column, QUOTE))
+                    .collect(),
+                on_delete: referential_action(&row.try_get::<String, _>(5)?),
+                on_update: referential_action(&row.try_get::<String, _>(6)?),
+                deferrable,
+            })
+        }
+        _ => {
+            let expr: String = row.try_get(9)?;
+            TableConstraint::Check(ast::Check {
+                name,
+                expr: Box::new(parse(&PostgreSqlDialect {}, &expr, |p| p.parse_expr())?),
+            })
+        }
+    })
+}
+
+/// `NO ACTION` is the default, so it is left out like in the migrations.
+fn referential_action(code: &str) -> Option<ReferentialAction> {
+    match code {
+        "r" => Some(ReferentialAction::Restrict),
+        "c" => Some(ReferentialAction::Cascade),
+        "n" => Some(ReferentialAction::SetNull),
+        "d" => Some(ReferentialAction::SetDefault),
+        _ => None,
+    }
+}
+
+pub(crate) fn column(
+    name: String,
+    r#type: &str,
+    not_null: bool,
+    default: Option<&str>,
+    generated: bool,
+    comment: Option<String>,
+) -> Result<Column> {
+    // serial columns are integers whose default is taken from a sequence.
+    let is_serial = matches!(default, Some(default) if default.starts_with("nextval("));
+    let (r#type, default) = match r#type {
+        "integer" if is_serial => ("SERIAL", None),
+        "bigint" if is_serial => ("BIGSERIAL", None),
+        _ => (r#type, default),
+    };
+    let dialect = PostgreSqlDialect {};
+    let mut options = vec![];
+    if not_null {
+        options.push(ColumnOption::NotNull);
+    }
+    match default {
+        // the expression of stored generated columns is kept as their default.
+        Some(expr) if generated => options.push(ColumnOption::Generated {
+            expr: parse(&dialect, expr, |p| p.parse_expr())?,
+            stored: true,
+        }),
+        Some(default) => options.push(ColumnOption::Default(parse(
+            &dialect,
+            strip_cast(default),
+            |p| p.parse_expr(),
+        )?)),
+        None => (),
+    }
+    Ok(Column {
+        name: ident(&name, QUOTE),
+        r#type: parse(&dialect, r#type, |p| p.parse_data_type())?,
+        options: options
+            .into_iter()
+            .map(|option| ColumnOptionDef { name: None, option })
+            .collect(),
+        renamed_from: None,
+        comment,
+    })
+}
+
+/// literals are printed with a cast to the type of the column, as in `'text'::text`.
+pub(crate) fn strip_cast(default: &str) -> &str {
+    match default.rfind("'::") {
+        Some(end) if default.starts_with('\'') && !default[end + 3..].contains('\'') => {
+            &default[..=end]
+        }
+        _ => default,
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+use sqlx::{Connection, SqliteConnection};
+
+// SQLite keeps the DDL of every table and index, up to date with later alterations.
+const SCHEMA: &str = "
+SELECT sql
+FROM sqlite_master
+WHERE type IN ('table', 'index')
+  AND sql IS NOT NULL
+  AND name NOT LIKE 'sqlite_%'
+  AND name <> '_sqlx_migrations'
+ORDER BY type = 'index', rowid";
+
+pub(super) async fn catalog(url: &str) -> Result<Catalog> {
+    let mut conn = SqliteConnection::connect(url).await?;
+    let schema: Vec<String> = sqlx::query_scalar(SCHEMA).fetch_all(&mut conn).await?;
+    conn.close().await?;
+    let mut out = Catalog::default();
+    for sql in schema {
+        let stmts = parser::Parser::parse_sql(&dialect::SQLiteDialect {}, &sql)?;
+        for stmt in stmts {
+            match stmt {
+                Statement::CreateTable(_) => out.tables.push(stmt.try_into()?),
+                Statement::CreateIndex(index) => table(&mut out, &index)?.indexes.push(index),
+                _ => (),
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn table<'a>(catalog: &'a mut Catalog, index: &CreateIndex) -> Result<&'a mut Table> {
+    let name = index
+        .table_name
+        .0
+        .iter()
+        .map(|ident| ident.value.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(".");
+    catalog
+        .tables
+        .iter_mut()
+        .find(|table| table.name() == name)
+        .ok_or_else(|| {
+            error!(
+                "could not find the table \"{}\" of the index \"{}\"",
+                name, index.name
+            )
+        })
+}
@@ -1,16 +1,20 @@
 use super::{
     actions::{action::Action, Actions},
     schema::Schema,
-    Report,
+    Difference, Report,
 };
-use crate::prelude::*;
+use crate::{prelude::*, settings::Settings};
 use fs::File;
 use std::io::Write;
+use sync::Arc;
+
+/// The statements that migrate a table to its model.
 #[derive(Debug)]
-pub(crate) struct Migration {
+pub struct Migration {
     up: Vec<Statement>,
     down: Vec<Statement>,
     name: ObjectName,
+    settings: Arc<Settings>,
 }
 
 fn timestamp() -> u128 {
@@ -21,16 +25,23 @@ fn timestamp() -> u128 {
 }
 
 impl Migration {
-    pub fn new(name: ObjectName) -> Self {
+    pub(crate) fn new(name: ObjectName) -> Self {
         Self {
             up: vec![],
             down: vec![],
             name,
+            settings: settings(),
         }
     }
 
-    pub fn create_down(&mut self, old: Schema, new: &Schema, table: &ObjectName) -> Result {
-        if let Some(target) = old.get_table(table) {
+    pub(crate) fn create_down(&mut self, old: Schema, new: &Schema, table: &Table) -> Result {
+        let old_table = old.get_table(&table.name).or_else(|| {
+            let renamed_from = table.renamed_from.as_ref()?;
+            old.get_table(renamed_from)
+        });
+        if let Some(target) = old_table {
+            let mut target = target.reverse_renames(table);
+            target.types = old.types_of(&target);
             let actions = Actions::new(&new, &target)?;
 
             self.down = actions
@@ -45,63 +56,131 @@ impl Migration {
             let drop_stmt = Statement::Drop(Drop {
                 object_type: ObjectType::Table,
                 if_exists: false,
-                names: vec![table.clone()],
-                cascade: DIALECT.supports_cascade(),
+                names: vec![table.name.clone()],
+                cascade: self.settings.dialect.supports_cascade(),
                 purge: false,
             });
             self.down.push(drop_stmt);
         }
+        // types created by the migration are dropped once nothing uses them.
+        for ty in &table.types {
+            if old.get_type(&ty.name).is_none() && new.get_type(&ty.name).is_some() {
+                self.down.push(Statement::Drop(Drop {
+                    object_type: ObjectType::Type,
+                    if_exists: false,
+                    names: vec![ty.name.clone()],
+                    cascade: false,
+                    purge: false,
+                }));
+            }
+        }
         Ok(())
     }
 
+    /// name of the table that is migrated.
+    pub fn name(&self) -> String {
+        self.name.to_string().to_lowercase()
+    }
+
     pub fn up(&self) -> &[Statement] {
         &self.up[..]
     }
 
+    /// statements that revert the migration. They are only generated for reversible migrations.
+    pub fn down(&self) -> &[Statement] {
+        &self.down[..]
+    }
+
+    /// the up statements, as they are written to the migration file.
+    pub fn up_sql(&self) -> String {
+        self.to_sql(&self.up)
+    }
+
+    /// the down statements, as they are written to the migration file.
+    pub fn down_sql(&self) -> String {
+        self.to_sql(&self.down)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.up.is_empty()
     }
-    pub fn push_up(&mut self, action: Action) -> Result {
+    pub(crate) fn push_up(&mut self, action: Action) -> Result {
         let stmts = action.to_statements()?;
         self.up.extend(stmts);
         Ok(())
     }
 
-    fn write_to_file(file_name: &str, stmts: &[Statement]) -> Result<()> {
-        let mut file = File::create(file_name)?;
+    fn to_sql(&self, stmts: &[Statement]) -> String {
+        let mut out = String::new();
         for stmt in stmts {
-            #[cfg(feature = "sqlformat")]
-            let stmt = Self::formatted_stmt(stmt);
-            write!(file, "{};\n\n", stmt)?;
+            let stmt = self.formatted_stmt(stmt);
+            out += &format!("{};\n\n", stmt);
         }
+        out
+    }
+
+    fn write_to_file(&self, file_name: &str, stmts: &[Statement]) -> Result<()> {
+        let mut file = File::create(file_name)?;
+        write!(file, "{}", self.to_sql(stmts))?;
         Ok(())
     }
 
-    pub fn commit(self) -> Result<Option<Report>> {
+    /// the formatted statements of the migration, without writing them.
+    pub(crate) fn difference(&self) -> Option<Difference> {
+        if self.is_empty() {
+            return None;
+        }
+        let format =
+            |stmts: &[Statement]| stmts.iter().map(|stmt| self.formatted_stmt(stmt)).collect();
+        Some(Difference {
+            name: self.name(),
+            up: format(&self.up),
+            down: if self.settings.reversible {
+                format(&self.down)
+            } else {
+                vec![]
+            },
+        })
+    }
+
+    pub(crate) fn commit(self) -> Result<Option<Report>> {
         if self.is_empty() {
             return Ok(None);
         }
+        let directory = self
+            .settings
+            .migrations_dir
+            .as_ref()
+            .ok_or_else(|| error!("the migrations directory is not set."))?;
         let timestamp = timestamp();
-        let file_name = format!("{}/{}_{}", *MIGRATIONS_DIR, timestamp, self.name);
+        let file_name = format!("{}/{}_{}", directory, timestamp, self.name);
 
-        let name = self.name.to_string().to_lowercase();
-        if !*MODELS_GENERATE_DOWN {
+        let name = self.name();
+        if !self.settings.reversible {
             let up = format!("{}.sql", file_name);
-            Self::write_to_file(&up, &self.up)?;
+            self.write_to_file(&up, &self.up)?;
             return Ok(Some(Report { timestamp, name }));
         } else {
             let up = format!("{}.up.sql", file_name);
             let down = format!("{}.down.sql", file_name);
-            Self::write_to_file(&up, &self.up)?;
-            Self::write_to_file(&down, &self.down)?;
+            self.write_to_file(&up, &self.up)?;
+            self.write_to_file(&down, &self.down)?;
             return Ok(Some(Report { timestamp, name }));
         };
     }
 
     #[cfg(feature = "sqlformat")]
-    fn formatted_stmt(stmt: &Statement) -> String {
+    fn formatted_stmt(&self, stmt: &Statement) -> String {
         use sqlformat::QueryParams;
         let stmt = format!("{}", stmt);
-        sqlformat::format(&stmt, &QueryParams::None, &FORMAT_OPTIONS)
+        match &self.settings.format {
+            Some(options) => sqlformat::format(&stmt, &QueryParams::None, options),
+            None => stmt,
+        }
+    }
+
+    #[cfg(not(feature = "sqlformat"))]
+    fn formatted_stmt(&self, stmt: &Statement) -> String {
+        stmt.to_string()
     }
 }
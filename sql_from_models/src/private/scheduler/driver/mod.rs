@@ -1,9 +1,10 @@
 use crate::prelude::*;
 pub(crate) mod actions;
+pub(crate) mod introspect;
 pub mod migration;
 mod queue;
 mod report;
-mod schema;
+pub(crate) mod schema;
 use actions::Actions;
 use queue::*;
 
@@ -14,6 +15,10 @@ pub(crate) struct Driver {
     result: Result<Schema>,
     queue: Queue,
     success: Vec<Report>,
+    differences: Vec<Difference>,
+    /// notes about the generated migrations, printed by the CLI.
+    warnings: Vec<String>,
+    registered: usize,
 }
 
 impl Driver {
@@ -23,26 +28,26 @@ impl Driver {
             result,
             queue: Queue::new(),
             success: vec![],
+            differences: vec![],
+            warnings: vec![],
+            registered: 0,
         }
     }
-    pub fn is_first(&self) -> bool {
-        self.queue.len() == 0
-    }
 
     pub fn register(&mut self, table: Table) {
+        self.registered += 1;
         self.queue.insert(table)
     }
     pub fn as_json(&self) -> String {
-        let error = if let Err(err) = &self.result {
-            err.as_json()
-        } else {
-            "null".into()
+        let output = Output {
+            version: super::PROTOCOL_VERSION,
+            registered: self.registered,
+            success: &self.success,
+            differences: &self.differences,
+            warnings: &self.warnings,
+            error: self.result.as_ref().err(),
         };
-        format!(
-            r#"{{"success": {success:?},"error": {error}}}"#,
-            success = &self.success,
-            error = error
-        )
+        serde_json::to_string(&output).unwrap()
     }
 
     pub fn migrate(&mut self) {
@@ -61,6 +66,19 @@ impl Driver {
         }
     }
 
+    /// returns the migrations of the registered tables without writing them.
+    pub fn plan(&mut self) -> Result<Vec<Migration>> {
+        self.queue.remove_unregistered();
+        let mut out = vec![];
+        while let Some(target) = self.queue.pop() {
+            out.extend(self.get_migrations(target)?);
+        }
+        if self.queue.len() != 0 {
+            return Err(Error::Cycle(self.queue.remaining_tables()));
+        }
+        Ok(out)
+    }
+
     pub fn migrate_table(&mut self, target: Table) {
         if let Err(error) = self.try_migration(target) {
             self.result = Err(error);
@@ -68,9 +86,23 @@ impl Driver {
     }
 
     fn try_migration(&mut self, target: Table) -> Result {
+        let warning = format!(
+            "the foreign keys of \"{}\" are only enforced by SQLite on connections \
+            that enable `PRAGMA foreign_keys = ON`.",
+            target.name()
+        );
+        let has_foreign_keys = !target.deps().is_empty();
         let migrations = self.get_migrations(target)?;
+        if !migrations.is_empty() && has_foreign_keys && dialect() == SQLite {
+            self.warnings.push(warning);
+        }
+        let check = settings().check;
         for mig in migrations {
-            if let Some(report) = mig.commit()? {
+            if check {
+                if let Some(difference) = mig.difference() {
+                    Difference::push(&mut self.differences, difference);
+                }
+            } else if let Some(report) = mig.commit()? {
                 self.success.push(report);
             }
         }
@@ -78,7 +110,6 @@ impl Driver {
     }
 
     fn get_migrations(&mut self, target: Table) -> Result<Vec<Migration>> {
-        println!("get_migrations");
         let schema = self.result.as_mut().map_err(|x| x.clone())?;
         let actions = Actions::new(&schema, &target)?;
 
@@ -89,7 +120,7 @@ impl Driver {
             for stmt in migr.up() {
                 schema.update(&stmt)?;
             }
-            migr.create_down(old_schema, schema, &target.name)?;
+            migr.create_down(old_schema, schema, &target)?;
         }
         Ok(migrations)
     }
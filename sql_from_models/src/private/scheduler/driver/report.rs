@@ -1,11 +1,56 @@
 use crate::prelude::*;
+use serde::{Serialize, Serializer};
+
+/// the report of a migration generation, the format read by the CLI.
+#[derive(Serialize)]
+pub(crate) struct Output<'a> {
+    pub version: u64,
+    pub registered: usize,
+    pub success: &'a [Report],
+    pub differences: &'a [Difference],
+    pub warnings: &'a [String],
+    pub error: Option<&'a Error>,
+}
+
 pub(crate) struct Report {
     pub timestamp: u128,
     pub name: String,
 }
 
-impl fmt::Debug for Report {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, r#"[{}, {:?}]"#, self.timestamp, self.name,)
+/// statements a table needs to match its model, reported instead of being written
+/// by `sql_from_models check` and `sql_from_models generate --dry-run`.
+pub(crate) struct Difference {
+    pub name: String,
+    pub up: Vec<String>,
+    /// empty unless the migrations are reversible.
+    pub down: Vec<String>,
+}
+
+impl Difference {
+    /// adds a difference to the list, grouped with the previous one if it belongs to the same table.
+    pub fn push(differences: &mut Vec<Difference>, difference: Difference) {
+        match differences.last_mut() {
+            Some(last) if last.name == difference.name => {
+                last.up.extend(difference.up);
+                // later migrations are reverted first.
+                let down = mem::replace(&mut last.down, difference.down);
+                last.down.extend(down);
+            }
+            _ => differences.push(difference),
+        }
+    }
+}
+
+/// serialized as `[name, up, down]`, the format read by the CLI.
+impl Serialize for Difference {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.name, &self.up, &self.down).serialize(serializer)
+    }
+}
+
+/// serialized as `[timestamp, name]`, the format read by the CLI.
+impl Serialize for Report {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.timestamp, &self.name).serialize(serializer)
     }
 }
@@ -5,21 +5,28 @@ use path::PathBuf;
 #[derive(Clone)]
 pub struct Schema {
     tables: HashMap<ObjectName, Table>,
+    /// PostgreSQL enum types.
+    types: HashMap<ObjectName, CreateType>,
 }
 
 impl Schema {
     pub fn new() -> Result<Self> {
         let mut out = Self {
             tables: HashMap::new(),
+            types: HashMap::new(),
         };
-        out.init()?;
+        match &settings().from_database {
+            Some(url) => out.introspect(url)?,
+            None => out.init()?,
+        }
         Ok(out)
     }
     #[cfg(test)]
-    fn _from_sql(sql: &str) -> Result<Self> {
+    pub(crate) fn _from_sql(sql: &str) -> Result<Self> {
         let stmts = parse_sql(sql)?;
         let mut out = Self {
             tables: HashMap::new(),
+            types: HashMap::new(),
         };
         for stmt in stmts {
             out.update(&stmt)?;
@@ -31,6 +38,36 @@ impl Schema {
         self.tables.get(&name)
     }
 
+    pub fn get_type(&self, name: &ObjectName) -> Option<&CreateType> {
+        self.types.get(&lowercase(name))
+    }
+
+    /// returns the enum types used by the columns of the table.
+    pub fn types_of(&self, table: &Table) -> Vec<CreateType> {
+        let mut out: Vec<CreateType> = vec![];
+        for col in &table.columns {
+            if let Some(name) = custom_type(&col.r#type) {
+                match self.get_type(name) {
+                    Some(ty) if !out.contains(ty) => out.push(ty.clone()),
+                    _ => (),
+                }
+            }
+        }
+        out
+    }
+
+    /// returns the columns of every table whose type is the enum `name`, or an array of it.
+    pub fn columns_of_type(&self, name: &ObjectName) -> Vec<(&ObjectName, &Column)> {
+        let name = lowercase(name);
+        self.tables
+            .values()
+            .flat_map(|table| table.columns.iter().map(move |col| (&table.name, col)))
+            .filter(
+                |(_, col)| matches!(custom_type(&col.r#type), Some(ty) if lowercase(ty) == name),
+            )
+            .collect()
+    }
+
     pub fn init(&mut self) -> Result {
         let stmts = self.get_statements()?;
         for stmt in stmts {
@@ -39,6 +76,18 @@ impl Schema {
         Ok(())
     }
 
+    /// rebuilds the schema from the live database at `url`.
+    pub fn introspect(&mut self, url: &str) -> Result {
+        let catalog = super::introspect::catalog(url)?;
+        for ty in catalog.types {
+            self.create_type(&ty)?;
+        }
+        for table in catalog.tables {
+            self.create_table(table)?;
+        }
+        Ok(())
+    }
+
     fn get_statements(&mut self) -> Result<Vec<Statement>> {
         let mut out = vec![];
         for path in self.read_dir()? {
@@ -55,8 +104,13 @@ impl Schema {
         Ok(out)
     }
     fn read_dir(&self) -> Result<Vec<PathBuf>> {
-        let directory = &*MIGRATIONS_DIR;
-        let mut dir: Vec<_> = read_dir(directory)
+        let directory = match &settings().migrations_dir {
+            // no migrations have been generated yet.
+            Some(directory) if !path::Path::new(directory).exists() => return Ok(vec![]),
+            Some(directory) => directory.clone(),
+            None => return Ok(vec![]),
+        };
+        let mut dir: Vec<_> = read_dir(&directory)
             .map_err(|_| error!("could not read the \"{}\" directiory.", directory))?
             .map(|x| x.unwrap().path())
             .collect();
@@ -73,7 +127,13 @@ impl Schema {
                 operation: AlterTableOperation::RenameTable { table_name },
             }) => self.rename_table(name, table_name),
             AlterTable(alter) => self.alter_table(&alter.name, &alter.operation),
-            Drop(drop) => self.drop_tables(drop),
+            CreateIndex(index) => self.create_index(index),
+            Drop(drop) if drop.object_type == ObjectType::Table => self.drop_tables(drop),
+            Drop(drop) if drop.object_type == ObjectType::Index => self.drop_indexes(drop),
+            CreateType(ty) => self.create_type(ty),
+            AlterType(alter) => self.alter_type(alter),
+            Drop(drop) if drop.object_type == ObjectType::Type => self.drop_types(drop),
+            Comment(comment) => self.comment(comment),
             _ => Ok(()),
         }
     }
@@ -85,14 +145,32 @@ impl Schema {
                 &old_name, &new_name
             )
         })?;
-        if !DIALECT.requires_move() {
-            self.cascade(&old_name);
-        }
         table.name = new_name.clone();
+        for index in &mut table.indexes {
+            index.table_name = new_name.clone();
+        }
         self.tables.insert(new_name.clone(), table);
+        // PostgreSQL, MySQL and SQLite keep the foreign keys that reference a renamed table,
+        // so they are renamed rather than dropped with `cascade`.
+        self.rename_references(old_name, new_name);
         Ok(())
     }
 
+    /// foreign keys follow the table they reference when it gets renamed.
+    fn rename_references(&mut self, old_name: &ObjectName, new_name: &ObjectName) {
+        use TableConstraint::*;
+        self.tables
+            .values_mut()
+            .flat_map(|table| table.constraints.iter_mut())
+            .for_each(|constr| {
+                if let ForeignKey(ast::ForeignKey { foreign_table, .. }) = constr {
+                    if foreign_table == old_name {
+                        *foreign_table = new_name.clone();
+                    }
+                }
+            });
+    }
+
     fn cascade(&mut self, name: &ObjectName) {
         use TableConstraint::*;
         self.tables //
@@ -102,7 +180,7 @@ impl Schema {
                     .constraints
                     .drain(..)
                     .filter(|constr| match constr {
-                        ForeignKey(ast::ForeignKey { foreign_table, .. }) => foreign_table == name,
+                        ForeignKey(ast::ForeignKey { foreign_table, .. }) => foreign_table != name,
                         _ => true,
                     })
                     .collect()
@@ -132,6 +210,150 @@ impl Schema {
         }
         Ok(())
     }
+    fn create_index(&mut self, index: &ast::CreateIndex) -> Result {
+        let name = lowercase(&index.table_name);
+        let table = self.tables.get_mut(&name).ok_or_else(|| {
+            error!(
+                "failed to load migrations. Could not find the table \"{}\" for the index \"{}\"",
+                name, index.name
+            )
+        })?;
+        let exists = table
+            .indexes
+            .iter()
+            .any(|i| i.name.to_string().to_lowercase() == index.name.to_string().to_lowercase());
+        if exists && !index.if_not_exists {
+            return Err(error!(
+                "attempting to create index \"{}\", but it already exists.",
+                index.name
+            ));
+        }
+        if !exists {
+            table.indexes.push(index.clone());
+        }
+        Ok(())
+    }
+
+    fn drop_indexes(&mut self, drop: &ast::Drop) -> Result {
+        for name in drop.names.iter() {
+            let name = name.to_string();
+            let found = self
+                .tables
+                .values_mut()
+                .any(|table| table.drop_index(&name));
+            if !drop.if_exists && !found {
+                return Err(error!(
+                    "failed to load migrations. Index \"{}\" cannot be dropped as it does not exist.",
+                    name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn create_type(&mut self, ty: &ast::CreateType) -> Result {
+        let name = lowercase(&ty.name);
+        if self.types.contains_key(&name) {
+            return Err(error!(
+                "attempting to create type \"{}\", but it already exists.",
+                name
+            ));
+        }
+        self.types
+            .insert(name.clone(), ast::CreateType { name, ..ty.clone() });
+        Ok(())
+    }
+
+    fn alter_type(&mut self, alter: &ast::AlterType) -> Result {
+        use AlterTypeOperation::*;
+        let name = lowercase(&alter.name);
+        let ty = self.types.get_mut(&name).ok_or_else(|| {
+            error!(
+                "failed to load migrations. Could not find the type \"{}\"",
+                name
+            )
+        })?;
+        match &alter.operation {
+            AddValue {
+                if_not_exists: true,
+                value,
+                ..
+            } if ty.labels.contains(value) => (),
+            AddValue {
+                value, position, ..
+            } => {
+                let index = match position {
+                    Some(EnumValuePosition::Before(next)) => {
+                        ty.labels.iter().position(|l| l == next)
+                    }
+                    Some(EnumValuePosition::After(prev)) => {
+                        ty.labels.iter().position(|l| l == prev).map(|i| i + 1)
+                    }
+                    None => Some(ty.labels.len()),
+                };
+                let index = index.ok_or_else(|| {
+                    error!(
+                        "failed to load migrations. Could not find the position of \"{}\" in the type \"{}\"",
+                        value, name
+                    )
+                })?;
+                ty.labels.insert(index, value.clone());
+            }
+            RenameValue { from, to } => {
+                for label in ty.labels.iter_mut().filter(|label| *label == from) {
+                    *label = to.clone();
+                }
+            }
+            RenameTo { new_name } => {
+                let new_name = lowercase(&ObjectName(vec![new_name.clone()]));
+                let mut ty = self.types.remove(&name).unwrap();
+                ty.name = new_name.clone();
+                self.types.insert(new_name.clone(), ty);
+                // columns follow the type they use.
+                self.tables
+                    .values_mut()
+                    .flat_map(|table| table.columns.iter_mut())
+                    .for_each(|col| match custom_type_mut(&mut col.r#type) {
+                        Some(ty) if lowercase(ty) == name => *ty = new_name.clone(),
+                        _ => (),
+                    });
+            }
+        }
+        Ok(())
+    }
+
+    fn drop_types(&mut self, drop: &ast::Drop) -> Result {
+        for name in drop.names.iter().map(lowercase) {
+            if self.types.remove(&name).is_none() && !drop.if_exists {
+                return Err(error!(
+                    "failed to load migrations. Type \"{}\" cannot be dropped as it does not exist.",
+                    name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn comment(&mut self, comment: &ast::Comment) -> Result {
+        let mut name = lowercase(&comment.name);
+        let column = match comment.object_type {
+            CommentObject::Table => None,
+            CommentObject::Column => name.0.pop(),
+        };
+        let table = self.tables.get_mut(&name).ok_or_else(|| {
+            error!(
+                "failed to load migrations. Could not find the table \"{}\"",
+                name
+            )
+        })?;
+        let text = comment.comment.clone().filter(|text| !text.is_empty());
+        match column {
+            Some(column) => table.get_col_mut(&column)?.comment = text,
+            None => table.comment = text,
+        }
+        Ok(())
+    }
+
     fn alter_table(&mut self, name: &ObjectName, op: &AlterTableOperation) -> Result {
         self.tables
             .get_mut(&name) //
@@ -158,6 +380,32 @@ impl Schema {
     }
 }
 
+/// the name of a custom type, looking through arrays.
+fn custom_type(data_type: &DataType) -> Option<&ObjectName> {
+    match data_type {
+        DataType::Custom(name) => Some(name),
+        DataType::Array(data_type) => custom_type(data_type),
+        _ => None,
+    }
+}
+
+fn custom_type_mut(data_type: &mut DataType) -> Option<&mut ObjectName> {
+    match data_type {
+        DataType::Custom(name) => Some(name),
+        DataType::Array(data_type) => custom_type_mut(data_type),
+        _ => None,
+    }
+}
+
+fn lowercase(name: &ObjectName) -> ObjectName {
+    ObjectName(
+        name.0
+            .iter()
+            .map(|ident| Ident::new(ident.value.to_lowercase()))
+            .collect(),
+    )
+}
+
 fn is_up_file(file_name: &PathBuf) -> bool {
     file_name.is_file() && !file_name.to_str().unwrap().contains(".down.sql")
 }
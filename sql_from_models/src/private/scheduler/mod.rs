@@ -5,31 +5,41 @@ pub mod table;
 use table::*;
 
 use driver::*;
-pub struct Scheduler(Mutex<Driver>);
+use linkme::distributed_slice;
 
-impl Scheduler {
-    pub(super) fn new() -> Self {
-        Self(Mutex::new(Driver::new()))
-    }
+/// version of the report read by the CLI. It must be increased when its format changes.
+pub const PROTOCOL_VERSION: u64 = 2;
 
-    pub fn register(&self, table: Table) {
-        let is_first;
-        {
-            let mut driver = self.0.lock().unwrap();
-            is_first = driver.is_first();
-            driver.register(table)
-            // release the lock
-        }
+/// name of the structures that derive `Model`, along with their `target` function.
+/// The registry is collected at link time, so it also contains the models of the dependencies.
+#[distributed_slice]
+pub static MODELS: [(&'static str, fn() -> Table)];
 
-        if is_first {
-            std::thread::sleep(time::Duration::from_millis(250));
-            self.commit()
-        }
+/// Entry point of the CLI, called by the harness binary it builds,
+/// which links the libraries of the project so their models are registered.
+/// When `MODELS_OUTPUT` is set, the migrations of every registered model are generated
+/// and the report is written to that file. Otherwise it does nothing.
+pub fn entry() {
+    let output = match env::var("MODELS_OUTPUT") {
+        Ok(output) => output,
+        Err(_) => return,
+    };
+    let json = generate(MODELS.iter());
+    if let Err(err) = fs::write(&output, json) {
+        panic!("could not write to {:?}: {}", output, err);
     }
-    fn commit(&self) {
-        let mut driver = self.0.lock().unwrap();
-        driver.migrate();
-        let json = driver.as_json();
-        println!("<SQLX-MODELS-OUTPUT>{0}</SQLX-MODELS-OUTPUT>", json);
+}
+
+/// generates the migrations of the models named by `MODELS_TABLE`, or of every model,
+/// and returns the report.
+fn generate<'a>(models: impl Iterator<Item = &'a (&'static str, fn() -> Table)>) -> String {
+    let filter = env::var("MODELS_TABLE").ok();
+    let mut driver = Driver::new();
+    for (name, target) in models {
+        if filter.is_none() || filter.as_deref() == Some(name) {
+            driver.register(target());
+        }
     }
+    driver.migrate();
+    driver.as_json()
 }
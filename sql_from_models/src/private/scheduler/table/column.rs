@@ -1,10 +1,32 @@
 use crate::prelude::*;
+use crate::private::scheduler::driver::actions::normalize;
 use sql_from_models_parser::{dialect::*, parser::*};
 #[derive(Clone, Debug, PartialEq)]
 pub struct Column {
     pub name: Ident,
     pub r#type: DataType,
     pub options: Vec<ColumnOptionDef>,
+    /// previous name of the column, declared with `#[model(renamed_from = "...")]`.
+    pub renamed_from: Option<Ident>,
+    /// comment of the column, taken from the doc comment of the field.
+    /// ```
+    /// use sql_from_models::{Dialect, Generator, Model};
+    /// /// Invoices sent to customers.
+    /// #[derive(Model)]
+    /// struct Invoice {
+    ///     /// Total in cents, taxes included.
+    ///     total: i64,
+    /// }
+    /// let generator = Generator::new(Dialect::MySQL).format(None);
+    /// let migrations = generator.generate(vec![generator.target::<Invoice>()]).unwrap();
+    /// assert_eq!(
+    ///     migrations[0].up_sql(),
+    ///     "CREATE TABLE invoice (\
+    ///     total BIGINT NOT NULL COMMENT 'Total in cents, taxes included.'\
+    ///     ) COMMENT = 'Invoices sent to customers.';\n\n"
+    /// );
+    /// ```
+    pub comment: Option<String>,
 }
 
 impl Column {
@@ -23,6 +45,8 @@ impl Column {
             name: Ident::new(name.to_lowercase()),
             r#type,
             options,
+            renamed_from: None,
+            comment: None,
         }
     }
 
@@ -30,7 +54,10 @@ impl Column {
         let dialect = GenericDialect {};
         let mut tokens = tokenizer::Tokenizer::new(&dialect, def);
         let mut parser = Parser::new(tokens.tokenize().unwrap(), &dialect);
-        let expr = parser.parse_expr().unwrap();
+        let expr = match parser.parse_expr().unwrap() {
+            Expr::Value(Value::Boolean(value)) => Expr::Value(boolean(value)),
+            expr => expr,
+        };
 
         let mut col = Column {
             name: Ident::new(name.to_lowercase()),
@@ -39,6 +66,8 @@ impl Column {
                 name: None,
                 option: ast::ColumnOption::Default(expr),
             }],
+            renamed_from: None,
+            comment: None,
         };
         if !is_nullable {
             col.options.push(ColumnOptionDef {
@@ -49,6 +78,77 @@ impl Column {
         col
     }
 
+    /// marks the column as generated from `expr`, which was validated by the derive macro.
+    /// ```
+    /// use sql_from_models::Model;
+    /// #[derive(Model)]
+    /// struct Rectangle {
+    ///     width: i32,
+    ///     height: i32,
+    ///     #[generated("width * height", stored)]
+    ///     area: Option<i32>,
+    ///     #[generated("width > height")]
+    ///     is_wide: Option<bool>,
+    /// }
+    /// ```
+    pub fn generated_as(&mut self, expr: &str, stored: bool) {
+        let dialect = GenericDialect {};
+        let mut tokens = tokenizer::Tokenizer::new(&dialect, expr);
+        let mut parser = Parser::new(tokens.tokenize().unwrap(), &dialect);
+        let expr = parser.parse_expr().unwrap();
+        self.options.push(ColumnOptionDef {
+            name: None,
+            option: ColumnOption::Generated { expr, stored },
+        });
+    }
+
+    /// generated columns cannot be written to.
+    pub(crate) fn is_generated(&self) -> bool {
+        self.options
+            .iter()
+            .any(|option| matches!(option.option, ColumnOption::Generated { .. }))
+    }
+
+    /// returns an error if the column uses options the dialect does not support.
+    pub(crate) fn check_dialect(&self) -> Result {
+        let is_virtual = self
+            .options
+            .iter()
+            .any(|option| matches!(option.option, ColumnOption::Generated { stored: false, .. }));
+        if is_virtual && !dialect().supports_virtual_columns() {
+            return Err(error!(
+                "the column \"{}\" is a virtual generated column, which is not supported by {:?}. \
+                help: consider using `#[generated(\"...\", stored)]`.",
+                self.name,
+                dialect()
+            ));
+        }
+        if dialect() == SQLite && self.is_auto_increment() && !self.is_primary() {
+            return Err(error!(
+                "the column \"{}\" is auto-incremented, which SQLite only supports \
+                on the single primary key of the table. \
+                help: consider declaring it with `#[primary_key]`.",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+
+    fn is_auto_increment(&self) -> bool {
+        self.options.iter().any(|option| match &option.option {
+            ColumnOption::DialectSpecific(tokens) => tokens
+                .iter()
+                .any(|token| token.to_string().eq_ignore_ascii_case("autoincrement")),
+            _ => false,
+        })
+    }
+
+    fn is_primary(&self) -> bool {
+        self.options
+            .iter()
+            .any(|option| matches!(option.option, ColumnOption::Unique { is_primary: true }))
+    }
+
     pub fn has_default(&self) -> bool {
         for option in &self.options {
             if matches!(option.option, ColumnOption::Default(_)) {
@@ -66,23 +166,295 @@ impl Column {
         }
         true
     }
+
+    /// the default of the column, as it is compared against other columns.
+    pub(crate) fn normalized_default(&self) -> Option<Expr> {
+        self.default()
+            .map(|expr| normalize_default(expr, &self.r#type))
+    }
+
+    /// the options of the column other than its default, as they are compared against other columns.
+    pub(crate) fn normalized_options(&self) -> collections::HashSet<(String, Option<Expr>)> {
+        self.options
+            .iter()
+            .filter(|opt| !matches!(opt.option, ColumnOption::Default(_)))
+            .map(normalize_option)
+            .collect()
+    }
+
+    fn default(&self) -> Option<&Expr> {
+        self.options.iter().find_map(|option| match &option.option {
+            ColumnOption::Default(expr) => Some(expr),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn alter(&mut self, op: &AlterColumnOperation) {
+        use AlterColumnOperation::*;
+        match op {
+            SetNotNull => {
+                self.options.retain(|opt| {
+                    !matches!(opt.option, ColumnOption::Null | ColumnOption::NotNull)
+                });
+                self.options.push(ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::NotNull,
+                });
+            }
+            DropNotNull => self
+                .options
+                .retain(|opt| !matches!(opt.option, ColumnOption::NotNull)),
+            SetDefault { value } => {
+                self.options
+                    .retain(|opt| !matches!(opt.option, ColumnOption::Default(_)));
+                self.options.push(ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Default(value.clone()),
+                });
+            }
+            DropDefault => self
+                .options
+                .retain(|opt| !matches!(opt.option, ColumnOption::Default(_))),
+            SetDataType { data_type, .. } => self.r#type = data_type.clone(),
+        }
+    }
+
+    /// value written to the null rows of the column before it is made `NOT NULL`:
+    /// its default, or the zero value of its type.
+    /// Types without a zero value, such as dates, return an error asking for a default.
+    pub(crate) fn backfill_value(&self) -> Result<Expr> {
+        if let Some(default) = self.default() {
+            return Ok(default.clone());
+        }
+        let value = match &self.r#type {
+            DataType::TinyInt(_)
+            | DataType::SmallInt(_)
+            | DataType::Int(_)
+            | DataType::BigInt(_)
+            | DataType::UnsignedTinyInt(_)
+            | DataType::UnsignedSmallInt(_)
+            | DataType::UnsignedInt(_)
+            | DataType::UnsignedBigInt(_)
+            | DataType::Decimal(..)
+            | DataType::Float(_)
+            | DataType::Real
+            | DataType::Double => Value::Number("0".parse().unwrap(), false),
+            DataType::Char(_) | DataType::Varchar(_) | DataType::Text | DataType::String => {
+                Value::SingleQuotedString(String::new())
+            }
+            DataType::Boolean => boolean(false),
+            DataType::Enum(labels) if !labels.is_empty() => {
+                Value::SingleQuotedString(labels[0].clone())
+            }
+            _ => {
+                return Err(error!(
+                    "the column \"{}\" of type {} becomes NOT NULL, \
+                    but there is no value to fill its null rows with. \
+                    help: consider adding a `#[default(...)]` to the field.",
+                    self.name, self.r#type
+                ))
+            }
+        };
+        Ok(Expr::Value(value))
+    }
+
+    /// returns the operations that turn the column into `target`.
+    /// `None` is returned if some change cannot be expressed with `ALTER COLUMN`.
+    pub(crate) fn alter_ops(&self, target: &Column) -> Option<Vec<AlterColumnOperation>> {
+        use AlterColumnOperation::*;
+        if other_options(self) != other_options(target) {
+            return None;
+        }
+        let mut ops = vec![];
+        let default = self.normalized_default();
+        let new_default = target.normalized_default();
+        let default_changed = default != new_default;
+        // the old default may not be valid for the new type.
+        if default_changed
+            && default.is_some()
+            && (new_default.is_none() || self.r#type != target.r#type)
+        {
+            ops.push(DropDefault);
+        }
+        if self.r#type != target.r#type {
+            // without `USING`, PostgreSQL only converts types with an implicit cast.
+            let column = Ident::with_quote('"', target.name.value.clone());
+            let using = Expr::Cast(Cast {
+                expr: Box::new(Expr::Identifier(column)),
+                data_type: target.r#type.clone(),
+            });
+            ops.push(SetDataType {
+                data_type: target.r#type.clone(),
+                using: Some(using),
+            });
+        }
+        if let Some(value) = target.default() {
+            if default_changed {
+                ops.push(SetDefault {
+                    value: value.clone(),
+                });
+            }
+        }
+        match (self.is_nullable(), target.is_nullable()) {
+            (true, false) => ops.push(SetNotNull),
+            (false, true) => ops.push(DropNotNull),
+            _ => (),
+        }
+        Some(ops)
+    }
+}
+
+/// converts the Rust constants used in `#[default(CONSTANT)]` to SQL literals.
+/// ```
+/// use sql_from_models::Model;
+/// const MAX_RETRIES: i32 = 3;
+/// #[derive(Model)]
+/// struct Job {
+///     #[default(MAX_RETRIES)]
+///     retries: i32,
+///     #[default(sql = "CURRENT_TIMESTAMP")]
+///     created_at: String,
+/// }
+/// ```
+pub trait DefaultLiteral {
+    fn default_literal(&self) -> String;
+}
+
+macro_rules! default_literal {
+    ($($ty:ty),*) => {
+        $(
+            impl DefaultLiteral for $ty {
+                fn default_literal(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+default_literal!(bool, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+impl DefaultLiteral for str {
+    fn default_literal(&self) -> String {
+        format!("'{}'", self.replace('\'', "''"))
+    }
+}
+
+impl DefaultLiteral for String {
+    fn default_literal(&self) -> String {
+        self.as_str().default_literal()
+    }
+}
+
+impl<T: DefaultLiteral + ?Sized> DefaultLiteral for &T {
+    fn default_literal(&self) -> String {
+        (**self).default_literal()
+    }
+}
+
+/// booleans are integers on MySQL and SQLite, older versions of SQLite do not accept `true` and `false`.
+fn boolean(value: bool) -> Value {
+    match dialect() {
+        PostgreSQL => Value::Boolean(value),
+        _ => Value::Number((value as u8).to_string().parse().unwrap(), false),
+    }
+}
+
+/// defaults are compared regardless of redundant parentheses, casts of literals, casing,
+/// or the way the dialect stores booleans.
+fn normalize_default(expr: &Expr, data_type: &DataType) -> Expr {
+    match normalize(expr) {
+        Expr::Cast(Cast { expr, .. }) if matches!(*expr, Expr::Value(_)) => {
+            normalize_default(&expr, data_type)
+        }
+        Expr::Value(Value::Number(number, _)) if *data_type == DataType::Boolean => {
+            match &*number.to_string() {
+                "0" => Expr::Value(Value::Boolean(false)),
+                "1" => Expr::Value(Value::Boolean(true)),
+                _ => Expr::Value(Value::Number(number, false)),
+            }
+        }
+        // `CURRENT_TIMESTAMP()` is the same as `CURRENT_TIMESTAMP`.
+        Expr::Function(function)
+            if function.args.is_empty()
+                && function.name.0.len() == 1
+                && function.name.0[0].value.starts_with("current_") =>
+        {
+            Expr::Identifier(function.name.0[0].clone())
+        }
+        expr => expr,
+    }
+}
+
+/// options are compared regardless of the casing of keywords,
+/// and the expressions they hold are compared with `normalize`.
+fn normalize_option(option: &ColumnOptionDef) -> (String, Option<Expr>) {
+    let name = match &option.name {
+        Some(name) => format!("CONSTRAINT {} ", name).to_lowercase(),
+        None => String::new(),
+    };
+    match &option.option {
+        ColumnOption::Generated { expr, stored } => (
+            format!("{}generated {}", name, stored),
+            Some(normalize(expr)),
+        ),
+        ColumnOption::Check(expr) => (format!("{}check", name), Some(normalize(expr))),
+        ColumnOption::Default(expr) => (format!("{}default", name), Some(normalize(expr))),
+        option => (format!("{}{}", name, option).to_lowercase(), None),
+    }
+}
+
+/// options that cannot be changed with `ALTER COLUMN`.
+fn other_options(col: &Column) -> collections::HashSet<(String, Option<Expr>)> {
+    col.options
+        .iter()
+        .filter(|opt| {
+            !matches!(
+                opt.option,
+                ColumnOption::Null | ColumnOption::NotNull | ColumnOption::Default(_)
+            )
+        })
+        .map(normalize_option)
+        .collect()
 }
 
 impl From<ColumnDef> for Column {
     fn from(col: ColumnDef) -> Self {
+        let mut comment = None;
+        let options = col
+            .options
+            .into_iter()
+            .filter(|opt| match &opt.option {
+                ColumnOption::Comment(text) => {
+                    comment = Some(text.clone()).filter(|text| !text.is_empty());
+                    false
+                }
+                _ => true,
+            })
+            .collect();
         Column {
             name: col.name,
-            options: col.options,
+            options,
             r#type: col.data_type,
+            renamed_from: None,
+            comment,
         }
     }
 }
 
 impl From<Column> for ColumnDef {
     fn from(col: Column) -> Self {
+        let mut options = col.options;
+        // PostgreSQL comments are set with `COMMENT ON` once the column is created.
+        if let (Some(comment), MySQL) = (col.comment, dialect()) {
+            options.push(ColumnOptionDef {
+                name: None,
+                option: ColumnOption::Comment(comment),
+            });
+        }
         ColumnDef {
             name: col.name,
-            options: col.options,
+            options,
             data_type: col.r#type,
             collation: None,
         }
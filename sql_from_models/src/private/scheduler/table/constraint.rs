@@ -35,6 +35,37 @@ pub fn unique(name: &str, fields: &[&str]) -> TableConstraint {
     })
 }
 
+pub fn check(name: &str, expr: &str) -> TableConstraint {
+    use sql_from_models_parser::dialect::GenericDialect;
+    // the expression was already validated by the derive macro.
+    let tokens = tokenizer::Tokenizer::new(&GenericDialect {}, expr)
+        .tokenize()
+        .unwrap();
+    let expr = parser::Parser::new(tokens, &GenericDialect {})
+        .parse_expr()
+        .unwrap();
+    Check(ast::Check {
+        name: Some(Ident::new(name)),
+        expr: Box::new(expr),
+    })
+}
+
+/// `deferrable` is empty, `"initially immediate"` or `"initially deferred"`.
+/// ```
+/// use sql_from_models::Model;
+/// #[derive(Model)]
+/// struct Author {
+///     #[primary_key]
+///     id: i32,
+/// }
+/// #[derive(Model)]
+/// struct Book {
+///     #[foreign_key(Author.id, on_delete = "set default", deferrable)]
+///     author_id: Option<i32>,
+///     #[foreign_key(Author.id, deferrable = "initially deferred")]
+///     editor_id: i32,
+/// }
+/// ```
 pub fn foreign_key(
     name: &str,
     local_col: &str,
@@ -42,27 +73,101 @@ pub fn foreign_key(
     foreign_col: &str,
     on_delete: &str,
     on_update: &str,
+    deferrable: &str,
+) -> TableConstraint {
+    composite_foreign_key(
+        name,
+        &[local_col],
+        foreign_table,
+        &[foreign_col],
+        on_delete,
+        on_update,
+        deferrable,
+    )
+}
+
+/// a foreign key spanning several columns. `local_cols` and `foreign_cols` are paired by position.
+/// It is generated for `#[foreign_key]` attributes declared on the structure:
+/// ```
+/// use sql_from_models::Model;
+/// #[derive(Model)]
+/// struct PostLike {
+///     #[primary_key(post_id)]
+///     profile_id: i32,
+///     post_id: i32,
+/// }
+/// #[derive(Model)]
+/// #[foreign_key(PostLike(profile_id, post_id), columns(profile, post), on_delete = "cascade")]
+/// struct LikeNotification {
+///     profile: i32,
+///     post: i32,
+/// }
+/// ```
+pub fn composite_foreign_key(
+    name: &str,
+    local_cols: &[&str],
+    foreign_table: &str,
+    foreign_cols: &[&str],
+    on_delete: &str,
+    on_update: &str,
+    deferrable: &str,
 ) -> TableConstraint {
     ForeignKey(ast::ForeignKey {
         name: Some(Ident::new(name)),
         foreign_table: ObjectName(vec![Ident::new(foreign_table)]),
-        referred_columns: vec![Ident::new(foreign_col)],
-        columns: vec![Ident::new(local_col)],
-        on_delete: match &*on_delete.to_lowercase() {
-            "cascade" => Some(ast::ReferentialAction::Cascade),
-            "no action" => Some(ast::ReferentialAction::NoAction),
-            "restrict" => Some(ast::ReferentialAction::Restrict),
-            "set default" => Some(ast::ReferentialAction::SetDefault),
-            "set null" => Some(ast::ReferentialAction::SetNull),
-            _ => None,
-        },
-        on_update: match &*on_update.to_lowercase() {
-            "cascade" => Some(ast::ReferentialAction::Cascade),
-            "no action" => Some(ast::ReferentialAction::NoAction),
-            "restrict" => Some(ast::ReferentialAction::Restrict),
-            "set default" => Some(ast::ReferentialAction::SetDefault),
-            "set null" => Some(ast::ReferentialAction::SetNull),
+        referred_columns: foreign_cols.iter().map(|col| Ident::new(*col)).collect(),
+        columns: local_cols.iter().map(|col| Ident::new(*col)).collect(),
+        on_delete: referential_action(on_delete),
+        on_update: referential_action(on_update),
+        deferrable: match &*deferrable.to_lowercase() {
+            "initially immediate" => Some(ast::Deferrable::InitiallyImmediate),
+            "initially deferred" => Some(ast::Deferrable::InitiallyDeferred),
             _ => None,
         },
     })
 }
+
+/// returns an error if the foreign key uses options the dialect does not support.
+/// The error names the field or structure of `table` the foreign key was declared on.
+pub(crate) fn check_dialect(constr: &TableConstraint, table: &Table) -> Result {
+    let fk = match constr {
+        ForeignKey(fk) => fk,
+        _ => return Ok(()),
+    };
+    let name = fk
+        .name
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    let declared_on = match table.declaration(&name) {
+        Some(declaration) => format!(" declared on `{}`", declaration),
+        None => String::new(),
+    };
+    let dialect = dialect();
+    for action in [&fk.on_delete, &fk.on_update].iter().copied().flatten() {
+        if *action == ast::ReferentialAction::SetDefault && !dialect.supports_set_default() {
+            return Err(error!(
+                "the foreign key \"{}\"{} uses SET DEFAULT, which is not supported by {:?}.",
+                name, declared_on, dialect
+            ));
+        }
+    }
+    if fk.deferrable.is_some() && !dialect.supports_deferrable() {
+        return Err(error!(
+            "the foreign key \"{}\"{} is deferrable, which is not supported by {:?}.",
+            name, declared_on, dialect
+        ));
+    }
+    Ok(())
+}
+
+fn referential_action(action: &str) -> Option<ast::ReferentialAction> {
+    match &*action.to_lowercase() {
+        "cascade" => Some(ast::ReferentialAction::Cascade),
+        "no action" => Some(ast::ReferentialAction::NoAction),
+        "restrict" => Some(ast::ReferentialAction::Restrict),
+        "set default" => Some(ast::ReferentialAction::SetDefault),
+        "set null" => Some(ast::ReferentialAction::SetNull),
+        _ => None,
+    }
+}
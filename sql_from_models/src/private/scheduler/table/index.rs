@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+pub fn create(name: &str, table: &str, fields: &[&str], unique: bool) -> CreateIndex {
+    let mut columns = vec![];
+    for field in fields {
+        columns.push(OrderByExpr {
+            expr: Expr::Identifier(Ident::new(*field)),
+            asc: None,
+            nulls_first: None,
+        });
+    }
+    CreateIndex {
+        name: ObjectName(vec![Ident::new(name)]),
+        table_name: ObjectName(vec![Ident::new(table)]),
+        columns,
+        unique,
+        if_not_exists: false,
+    }
+}
+
+/// returns true if the index covers the column.
+pub(crate) fn covers(index: &CreateIndex, column: &Ident) -> bool {
+    index.columns.iter().any(|col| is_column(col, column))
+}
+
+/// removes the column from the columns of the index.
+pub(crate) fn remove_column(index: &mut CreateIndex, column: &Ident) {
+    index.columns.retain(|col| !is_column(col, column));
+}
+
+/// renames the column in the columns of the index.
+pub(crate) fn rename_column(index: &mut CreateIndex, old: &Ident, new: &Ident) {
+    for col in &mut index.columns {
+        if is_column(col, old) {
+            col.expr = Expr::Identifier(new.clone());
+        }
+    }
+}
+
+fn is_column(expr: &OrderByExpr, column: &Ident) -> bool {
+    match &expr.expr {
+        Expr::Identifier(ident) => ident.value.to_lowercase() == column.value.to_lowercase(),
+        _ => false,
+    }
+}
@@ -1,9 +1,12 @@
 use crate::prelude::*;
 mod column;
 pub mod constraint;
+pub mod index;
 use crate::private::scheduler::driver::actions::Compare;
 pub use column::*;
 
+/// the table a model is mapped to, returned by `Model::target` and `Generator::target`.
+/// It is passed to `Generator::generate` to plan its migrations.
 #[derive(Clone, Debug)]
 pub struct Table {
     pub(crate) name: ObjectName,
@@ -11,6 +14,17 @@ pub struct Table {
     pub or_replace: bool,
     pub columns: Vec<Column>,
     pub constraints: Vec<TableConstraint>,
+    pub indexes: Vec<CreateIndex>,
+    /// enum types used by the columns, created before the table.
+    pub types: Vec<CreateType>,
+    /// previous name of the table, declared with `#[model(renamed_from = "...")]`.
+    pub renamed_from: Option<ObjectName>,
+    /// comment of the table, taken from the doc comment of the structure.
+    pub comment: Option<String>,
+    /// the structure or field each constraint was declared on, by constraint name.
+    /// It is set by the derive so the options a dialect rejects point at the model.
+    #[doc(hidden)]
+    pub declared_on: Vec<(String, &'static str)>,
 }
 
 impl Table {
@@ -19,11 +33,24 @@ impl Table {
             name: ObjectName(vec![Ident::new(name)]),
             columns: vec![],
             constraints: vec![],
+            indexes: vec![],
+            types: vec![],
             if_not_exists: false,
             or_replace: false,
+            renamed_from: None,
+            comment: None,
+            declared_on: vec![],
         }
     }
 
+    /// where the constraint was declared, such as `Post::author_id`, if it comes from a model.
+    pub(crate) fn declaration(&self, constr: &str) -> Option<&'static str> {
+        self.declared_on
+            .iter()
+            .find(|(name, _)| name == constr)
+            .map(|(_, declaration)| *declaration)
+    }
+
     pub(crate) fn name(&self) -> String {
         self.name.to_string().to_lowercase()
     }
@@ -40,12 +67,32 @@ impl Table {
             .collect()
     }
 
+    /// returns a copy of the table whose name and columns are marked as renamed
+    /// from the names they were given in `target`. It is used to revert renames.
+    pub(crate) fn reverse_renames(&self, target: &Table) -> Table {
+        let mut table = self.clone();
+        if table.name != target.name {
+            table.renamed_from = Some(target.name.clone());
+        }
+        for col in &mut table.columns {
+            col.renamed_from = target
+                .columns
+                .iter()
+                .find(|new| new.renamed_from() == col.name().ok())
+                .map(|new| new.name.clone());
+        }
+        table
+    }
+
     pub(super) fn alter_table(&mut self, op: &AlterTableOperation) -> Result {
         use AlterTableOperation::*;
         match op {
             AddColumn { column_def } => self.columns.push(column_def.clone().into()),
             AddConstraint(constr) => self.constraints.push(constr.clone()),
             DropConstraint { name, .. } => self.drop_constraint(name.to_string()),
+            DropIndex { name } => {
+                self.drop_index(&name.to_string());
+            }
 
             DropColumn {
                 column_name,
@@ -56,11 +103,32 @@ impl Table {
                 old_column_name,
                 new_column_name,
             } => self.rename_col(old_column_name, new_column_name),
+            AlterColumn { column_name, op } => self.get_col_mut(column_name)?.alter(op),
+            ModifyColumn { column_def } => {
+                *self.get_col_mut(&column_def.name)? = column_def.clone().into();
+            }
+            // MySQL removes comments by setting them to an empty string.
+            SetComment { comment } => {
+                self.comment = Some(comment.clone()).filter(|comment| !comment.is_empty())
+            }
             op => return Err(error!("unsupported operation: \"{}\"", op)),
         }
         Ok(())
     }
 
+    pub(crate) fn get_col_mut(&mut self, name: &Ident) -> Result<&mut Column> {
+        let table = &self.name;
+        self.columns
+            .iter_mut()
+            .find(|col| col.name.value.to_lowercase() == name.value.to_lowercase())
+            .ok_or_else(|| {
+                error!(
+                    "failed to load migrations. Could not find the column \"{}\" in the table \"{}\"",
+                    name, table
+                )
+            })
+    }
+
     pub(super) fn drop_col(&mut self, name: &Ident, if_exists: bool) {
         let len = self.columns.len();
         self.columns = self
@@ -73,6 +141,24 @@ impl Table {
             "Column \"{}\" does not exists",
             name
         );
+        // PostgreSQL drops the indexes that cover the column, while MySQL removes the
+        // column from them and only drops the indexes left without columns.
+        if matches!(dialect(), MySQL) {
+            for index in &mut self.indexes {
+                index::remove_column(index, name);
+            }
+            self.indexes.retain(|index| !index.columns.is_empty());
+        } else {
+            self.indexes.retain(|index| !index::covers(index, name));
+        }
+    }
+
+    /// drops the index and returns true if it was found.
+    pub(crate) fn drop_index(&mut self, rm_name: &str) -> bool {
+        let len = self.indexes.len();
+        self.indexes
+            .retain(|index| index.name.to_string().to_lowercase() != rm_name.to_lowercase());
+        len != self.indexes.len()
     }
 
     pub fn drop_constraint(&mut self, rm_name: String) {
@@ -88,12 +174,15 @@ impl Table {
             .iter()
             .map(Clone::clone)
             .map(|mut col| {
-                if &col.name == old {
+                if col.name.value.to_lowercase() == old.value.to_lowercase() {
                     col.name = new.clone();
                 }
                 col
             })
-            .collect()
+            .collect();
+        for index in &mut self.indexes {
+            index::rename_column(index, old, new);
+        }
     }
 }
 
@@ -114,6 +203,11 @@ impl TryFrom<Statement> for Table {
                 or_replace: false,
                 columns: table.columns.into_iter().map(Into::into).collect(),
                 constraints: table.constraints,
+                indexes: vec![],
+                types: vec![],
+                renamed_from: None,
+                comment: table.comment.filter(|comment| !comment.is_empty()),
+                declared_on: vec![],
             })
         } else {
             Err(error!(
@@ -143,6 +237,8 @@ impl From<Table> for Statement {
             query: None,
             without_rowid: false,
             like: None,
+            // PostgreSQL comments are set with `COMMENT ON` once the table is created.
+            comment: table.comment.filter(|_| matches!(dialect(), MySQL)),
         }))
     }
 }
@@ -0,0 +1,101 @@
+//! Options that drive the generation of migrations.
+//! They are read from environment variables set by the CLI,
+//! unless a `Generator` is running on the current thread.
+use crate::prelude::*;
+use cell::RefCell;
+#[cfg(feature = "sqlformat")]
+use sqlformat::{FormatOptions, Indent};
+use sync::Arc;
+use url::Url;
+
+#[cfg(feature = "sqlformat")]
+pub(crate) static FORMAT_OPTIONS: FormatOptions = FormatOptions {
+    indent: Indent::Spaces(4),
+    uppercase: Some(true),
+    lines_between_queries: 2,
+    ignore_case_convert: None,
+};
+
+static ENV: Lazy<Arc<Settings>> = Lazy::new(|| Arc::new(Settings::from_env()));
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<Settings>>> = const { RefCell::new(None) };
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Settings {
+    pub dialect: Dialect,
+    /// folder the current schema is read from. The schema is empty without it.
+    pub migrations_dir: Option<String>,
+    /// URL of the database the current schema is read from instead of the migrations.
+    pub from_database: Option<String>,
+    /// down migrations are generated along with up migrations.
+    pub reversible: bool,
+    /// unsigned integers are checked to be positive on PostgreSQL.
+    pub unsigned_checks: bool,
+    /// migrations are reported as differences instead of being written.
+    pub check: bool,
+    /// migrations are written unformatted without it.
+    #[cfg(feature = "sqlformat")]
+    pub format: Option<FormatOptions<'static>>,
+}
+
+impl Settings {
+    pub fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            migrations_dir: None,
+            from_database: None,
+            reversible: false,
+            unsigned_checks: false,
+            check: false,
+            #[cfg(feature = "sqlformat")]
+            format: Some(FORMAT_OPTIONS.clone()),
+        }
+    }
+
+    /// the dialect is taken from `DATABASE_URL`. Without a valid URL, as when the models are used
+    /// outside of the CLI and of a `Generator`, the generic dialect is used.
+    fn from_env() -> Self {
+        let database_url = env::var("DATABASE_URL").ok();
+        let dialect = match database_url.as_deref().map(Url::parse) {
+            Some(Ok(url)) => Dialect::from_url(&url),
+            _ => Dialect::Any,
+        };
+        let is_true = |var| env::var(var).as_deref() == Ok("true");
+        let mut out = Self::new(dialect);
+        out.migrations_dir = env::var("MIGRATIONS_DIR").ok();
+        out.reversible = is_true("MODELS_GENERATE_DOWN");
+        out.unsigned_checks = is_true("MODELS_UNSIGNED_CHECKS");
+        out.check = is_true("MODELS_CHECK");
+        if is_true("MODELS_FROM_DATABASE") {
+            out.from_database = database_url;
+        }
+        out
+    }
+}
+
+/// the settings of the running `Generator`, or the ones of the environment.
+pub(crate) fn settings() -> Arc<Settings> {
+    CURRENT
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(|| ENV.clone())
+}
+
+pub(crate) fn dialect() -> Dialect {
+    settings().dialect
+}
+
+/// runs `func` with `settings` as the settings of the current thread.
+pub(crate) fn scope<T>(settings: Settings, func: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Arc<Settings>>);
+    impl ops::Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+    let previous = CURRENT.with(|current| current.replace(Some(Arc::new(settings))));
+    let _restore = Restore(previous);
+    func()
+}
@@ -1 +1,1667 @@
-use crate::private::{driver::schema::Schema, scheduler, *};
+use crate::{prelude::*, Array, BigSerial, Decimal, Generator, Serial, SqlEnum, VarChar};
+use crate::private::scheduler::driver::{actions::Actions, schema::Schema};
+
+fn init() {
+    env::set_var("DATABASE_URL", "postgres://localhost/models");
+}
+
+/// returns the up statements generated to migrate from `current` to `target`.
+fn migrate(current: &str, target: &Table) -> Vec<String> {
+    init();
+    let schema = Schema::_from_sql(current).unwrap();
+    Actions::new(&schema, target)
+        .unwrap()
+        .as_migrations()
+        .unwrap()
+        .iter()
+        .flat_map(|migr| migr.up().iter().map(ToString::to_string).collect::<Vec<_>>())
+        .collect()
+}
+
+/// returns the up migration of the model `M` on an empty schema, generated by `generator`.
+fn up_sql<M: Model>(generator: Generator) -> String {
+    let migrations = generator.generate(vec![generator.target::<M>()]);
+    migrations.unwrap()[0].up_sql()
+}
+
+#[test]
+fn rename_column() {
+    let mut target = Table::new("post");
+    let mut title = Column::new("title", DataType::Text, false);
+    title.renamed_from = Some(Ident::new("name"));
+    target.columns.push(title);
+
+    let stmts = migrate("CREATE TABLE post (name TEXT NOT NULL);", &target);
+    assert_eq!(stmts, ["ALTER TABLE post RENAME COLUMN name TO title"]);
+}
+
+#[test]
+fn rename_column_with_new_type() {
+    let mut target = Table::new("post");
+    let mut title = Column::new("title", DataType::Varchar(Some(255)), false);
+    title.renamed_from = Some(Ident::new("name"));
+    target.columns.push(title);
+
+    let stmts = migrate("CREATE TABLE post (name TEXT NOT NULL);", &target);
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TABLE post RENAME COLUMN name TO title",
+            "ALTER TABLE post ALTER COLUMN title SET DATA TYPE VARCHAR(255) \
+            USING CAST(\"title\" AS VARCHAR(255))"
+        ]
+    );
+}
+
+#[test]
+fn rename_table() {
+    let mut target = Table::new("writer");
+    target.renamed_from = Some("author".into());
+    target
+        .columns
+        .push(Column::new("id", DataType::Int(None), false));
+
+    let stmts = migrate("CREATE TABLE author (id INT NOT NULL);", &target);
+    assert_eq!(stmts, ["ALTER TABLE author RENAME TO writer"]);
+}
+
+#[test]
+fn rename_table_updates_foreign_keys() {
+    init();
+    let mut schema = Schema::_from_sql(
+        "CREATE TABLE author (id INT NOT NULL);
+        CREATE TABLE book (
+            author_id INT NOT NULL,
+            CONSTRAINT book_foreign_author_id_id FOREIGN KEY (author_id) REFERENCES author(id)
+        );",
+    )
+    .unwrap();
+    for stmt in parse_sql("ALTER TABLE author RENAME TO writer;").unwrap() {
+        schema.update(&stmt).unwrap();
+    }
+    let book = schema.get_table(&"book".into()).unwrap();
+    assert_eq!(book.deps(), ["writer"]);
+}
+
+#[test]
+fn drop_table_cascade_keeps_other_foreign_keys() {
+    init();
+    let mut schema = Schema::_from_sql(
+        "CREATE TABLE author (id INT NOT NULL);
+        CREATE TABLE publisher (id INT NOT NULL);
+        CREATE TABLE book (
+            author_id INT NOT NULL,
+            publisher_id INT NOT NULL,
+            CONSTRAINT book_foreign_author_id_id FOREIGN KEY (author_id) REFERENCES author(id),
+            CONSTRAINT book_foreign_publisher_id_id FOREIGN KEY (publisher_id) REFERENCES publisher(id)
+        );",
+    )
+    .unwrap();
+    for stmt in parse_sql("DROP TABLE author CASCADE;").unwrap() {
+        schema.update(&stmt).unwrap();
+    }
+    let book = schema.get_table(&"book".into()).unwrap();
+    assert_eq!(book.deps(), ["publisher"]);
+}
+
+fn notification_with_like(local_cols: &[&str]) -> Table {
+    let mut target = Table::new("notification");
+    for col in ["profile", "post"] {
+        target
+            .columns
+            .push(Column::new(col, DataType::Int(None), false));
+    }
+    target.constraints.push(constraint::composite_foreign_key(
+        "notification_foreign_profile_post_profile_id_post_id",
+        local_cols,
+        "postlike",
+        &["profile_id", "post_id"],
+        "cascade",
+        "",
+        "",
+    ));
+    target
+}
+
+const POST_LIKE: &str = "CREATE TABLE postlike (
+    profile_id INT NOT NULL,
+    post_id INT NOT NULL,
+    CONSTRAINT postlike_primary_profile_id_post_id PRIMARY KEY (profile_id, post_id)
+);";
+
+#[test]
+fn composite_foreign_key() {
+    let stmts = migrate(POST_LIKE, &notification_with_like(&["profile", "post"]));
+    assert_eq!(
+        stmts,
+        ["CREATE TABLE notification (\
+            profile INTEGER NOT NULL, \
+            post INTEGER NOT NULL, \
+            CONSTRAINT notification_foreign_profile_post_profile_id_post_id \
+            FOREIGN KEY (profile, post) REFERENCES postlike(profile_id, post_id) ON DELETE CASCADE\
+        )"]
+    );
+}
+
+#[test]
+fn composite_foreign_key_pairs_are_compared() {
+    let current = format!(
+        "{}
+        CREATE TABLE notification (
+            profile INT NOT NULL,
+            post INT NOT NULL,
+            CONSTRAINT notification_foreign_profile_post_profile_id_post_id
+            FOREIGN KEY (post, profile) REFERENCES postlike(post_id, profile_id) ON DELETE CASCADE
+        );",
+        POST_LIKE
+    );
+    let stmts = migrate(&current, &notification_with_like(&["profile", "post"]));
+    assert!(stmts.is_empty());
+
+    let stmts = migrate(&current, &notification_with_like(&["post", "profile"]));
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TABLE notification DROP CONSTRAINT notification_foreign_profile_post_profile_id_post_id CASCADE",
+            "ALTER TABLE notification ADD CONSTRAINT notification_foreign_profile_post_profile_id_post_id \
+            FOREIGN KEY (post, profile) REFERENCES postlike(profile_id, post_id) ON DELETE CASCADE"
+        ]
+    );
+}
+
+fn book_with_author(on_delete: &str, deferrable: &str) -> Table {
+    let mut target = Table::new("book");
+    target
+        .columns
+        .push(Column::new("author_id", DataType::Int(None), true));
+    target.constraints.push(constraint::foreign_key(
+        "book_foreign_author_id_id",
+        "author_id",
+        "author",
+        "id",
+        on_delete,
+        "",
+        deferrable,
+    ));
+    target
+}
+
+#[test]
+fn referential_actions_per_dialect() {
+    let up = |dialect, target| {
+        let generator = Generator::new(dialect).format(None);
+        generator
+            .generate(vec![target])
+            .map(|migrations| migrations[0].up_sql())
+            .map_err(|err| err.to_string())
+    };
+    assert_eq!(
+        up(PostgreSQL, book_with_author("set default", "initially deferred")).unwrap(),
+        "CREATE TABLE book (author_id INTEGER, \
+        CONSTRAINT book_foreign_author_id_id FOREIGN KEY (author_id) REFERENCES author(id) \
+        ON DELETE SET DEFAULT DEFERRABLE INITIALLY DEFERRED);\n\n"
+    );
+    assert!(up(SQLite, book_with_author("set default", "initially immediate")).is_ok());
+    assert_eq!(
+        up(MySQL, book_with_author("set default", "")).unwrap_err(),
+        "the foreign key \"book_foreign_author_id_id\" uses SET DEFAULT, which is not supported by MySQL."
+    );
+    assert_eq!(
+        up(MySQL, book_with_author("cascade", "initially deferred")).unwrap_err(),
+        "the foreign key \"book_foreign_author_id_id\" is deferrable, which is not supported by MySQL."
+    );
+}
+
+#[derive(crate::Model)]
+struct Writer {
+    #[primary_key]
+    id: i32,
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Novel {
+    #[foreign_key(Writer.id, on_delete = "set default")]
+    writer_id: i32,
+}
+
+#[test]
+fn unsupported_foreign_keys_name_their_field() {
+    let generator = Generator::new(MySQL).format(None);
+    let tables = vec![generator.target::<Writer>(), generator.target::<Novel>()];
+    assert_eq!(
+        generator.generate(tables).unwrap_err().to_string(),
+        "the foreign key \"novel_foreign_writer_id_id\" declared on `Novel::writer_id` \
+        uses SET DEFAULT, which is not supported by MySQL."
+    );
+}
+
+#[test]
+fn sqlite_foreign_keys_are_warned_about() {
+    let dir = tempfile::tempdir().unwrap();
+    let settings = crate::settings::Settings {
+        migrations_dir: Some(dir.path().to_str().unwrap().into()),
+        ..crate::settings::Settings::new(SQLite)
+    };
+    let report = crate::settings::scope(settings, || {
+        let mut driver = crate::private::scheduler::driver::Driver::new();
+        driver.register(book_with_author("cascade", ""));
+        driver.migrate();
+        driver.as_json()
+    });
+    let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(
+        report["warnings"],
+        serde_json::json!([
+            "the foreign keys of \"book\" are only enforced by SQLite on connections \
+            that enable `PRAGMA foreign_keys = ON`."
+        ])
+    );
+}
+
+#[test]
+fn checks_report_differences_without_writing() {
+    let dir = tempfile::tempdir().unwrap();
+    let settings = crate::settings::Settings {
+        migrations_dir: Some(dir.path().to_str().unwrap().into()),
+        check: true,
+        format: None,
+        ..crate::settings::Settings::new(PostgreSQL)
+    };
+    let report = crate::settings::scope(settings, || {
+        let mut driver = crate::private::scheduler::driver::Driver::new();
+        driver.register(post_with_author());
+        driver.migrate();
+        driver.as_json()
+    });
+    let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(report["success"], serde_json::json!([]));
+    assert_eq!(report["differences"][0][0], "post");
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Greeting {
+    #[default("it's me")]
+    text: String,
+    #[default("")]
+    signature: String,
+}
+
+#[test]
+fn string_defaults_are_escaped() {
+    let generator = Generator::new(PostgreSQL).format(None);
+    let migrations = generator
+        .generate(vec![generator.target::<Greeting>()])
+        .unwrap();
+    assert_eq!(
+        migrations[0].up_sql(),
+        "CREATE TABLE greeting (text TEXT DEFAULT 'it''s me' NOT NULL, \
+        signature TEXT DEFAULT '' NOT NULL);\n\n"
+    );
+}
+
+#[test]
+fn reports_are_valid_json() {
+    use crate::private::scheduler::driver::{Difference, Output};
+    let comment = "COMMENT ON COLUMN post.title IS 'caf\u{e9}\u{301} \u{1b}[1m'";
+    let differences = [Difference {
+        name: "post".into(),
+        up: vec![comment.into()],
+        down: vec![],
+    }];
+    let error = error!("invalid \u{7} value");
+    let output = Output {
+        version: 2,
+        registered: 1,
+        success: &[],
+        differences: &differences,
+        warnings: &[],
+        error: Some(&error),
+    };
+    let report = serde_json::to_string(&output).unwrap();
+    let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(
+        report["differences"],
+        serde_json::json!([["post", [comment], []]])
+    );
+    assert_eq!(
+        report["error"],
+        serde_json::json!({"kind": "error", "message": "invalid \u{7} value"})
+    );
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+#[index(title_text, unique)]
+struct Draft {
+    #[primary_key]
+    #[model(column = "id")]
+    draft_id: i32,
+    title_text: String,
+    #[model(skip)]
+    word_count: usize,
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Review {
+    #[foreign_key(Draft.draft_id)]
+    draft: i32,
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+#[foreign_key(Draft(draft_id), columns(draft))]
+struct Reply {
+    draft: i32,
+}
+
+#[test]
+fn skipped_fields_have_no_column() {
+    let generator = Generator::new(PostgreSQL).format(None);
+    let tables = vec![
+        generator.target::<Draft>(),
+        generator.target::<Review>(),
+        generator.target::<Reply>(),
+    ];
+    // tables that do not depend on each other are not ordered.
+    let mut up: Vec<_> = generator
+        .generate(tables)
+        .unwrap()
+        .iter()
+        .map(|migr| migr.up_sql())
+        .collect();
+    up.sort();
+    assert_eq!(
+        up,
+        [
+            "CREATE TABLE draft (id INTEGER NOT NULL, title_text TEXT NOT NULL, \
+            CONSTRAINT draft_primary_id PRIMARY KEY (id));\n\n\
+            CREATE UNIQUE INDEX draft_index_title_text ON draft(title_text);\n\n",
+            "CREATE TABLE reply (draft INTEGER NOT NULL, \
+            CONSTRAINT reply_foreign_draft_id FOREIGN KEY (draft) REFERENCES draft(id));\n\n",
+            "CREATE TABLE review (draft INTEGER NOT NULL, \
+            CONSTRAINT review_foreign_draft_id FOREIGN KEY (draft) REFERENCES draft(id));\n\n",
+        ]
+    );
+    assert_eq!(Draft::__models_column_draft_id, "id");
+}
+
+#[test]
+fn deferrable_is_compared() {
+    let current = "CREATE TABLE book (
+        author_id INT,
+        CONSTRAINT book_foreign_author_id_id FOREIGN KEY (author_id) REFERENCES author(id) DEFERRABLE
+    );";
+    assert!(migrate(current, &book_with_author("", "initially immediate")).is_empty());
+    assert_eq!(
+        migrate(current, &book_with_author("", "initially deferred")),
+        [
+            "ALTER TABLE book DROP CONSTRAINT book_foreign_author_id_id CASCADE",
+            "ALTER TABLE book ADD CONSTRAINT book_foreign_author_id_id \
+            FOREIGN KEY (author_id) REFERENCES author(id) DEFERRABLE INITIALLY DEFERRED"
+        ]
+    );
+}
+
+fn post_with_author() -> Table {
+    let mut target = Table::new("post");
+    target
+        .columns
+        .push(Column::new("author", DataType::Int(None), false));
+    target
+}
+
+#[test]
+fn create_index() {
+    let mut target = post_with_author();
+    target
+        .indexes
+        .push(index::create("post_index_author", "post", &["author"], false));
+
+    let stmts = migrate("CREATE TABLE post (author INT NOT NULL);", &target);
+    assert_eq!(stmts, ["CREATE INDEX post_index_author ON post(author)"]);
+}
+
+#[test]
+fn update_index() {
+    let mut target = post_with_author();
+    target
+        .indexes
+        .push(index::create("post_index_author", "post", &["author"], true));
+
+    let stmts = migrate(
+        "CREATE TABLE post (author INT NOT NULL);
+        CREATE INDEX post_index_author ON post (author);",
+        &target,
+    );
+    assert_eq!(
+        stmts,
+        [
+            "DROP INDEX post_index_author",
+            "CREATE UNIQUE INDEX post_index_author ON post(author)"
+        ]
+    );
+}
+
+#[test]
+fn drop_index() {
+    let stmts = migrate(
+        "CREATE TABLE post (author INT NOT NULL);
+        CREATE INDEX post_index_author ON post (author);",
+        &post_with_author(),
+    );
+    assert_eq!(stmts, ["DROP INDEX post_index_author"]);
+}
+
+#[test]
+fn dropped_indexes_are_replayed() {
+    let stmts = migrate(
+        "CREATE TABLE post (author INT NOT NULL);
+        CREATE INDEX post_index_author ON post (author);
+        DROP INDEX post_index_author;",
+        &post_with_author(),
+    );
+    assert!(stmts.is_empty());
+}
+
+#[test]
+fn renamed_columns_are_renamed_in_indexes() {
+    init();
+    let mut schema = Schema::_from_sql(
+        "CREATE TABLE post (foo INT NOT NULL);
+        CREATE INDEX post_index_foo ON post (foo);",
+    )
+    .unwrap();
+    for stmt in parse_sql("ALTER TABLE post RENAME COLUMN Foo TO bar;").unwrap() {
+        schema.update(&stmt).unwrap();
+    }
+    let post = schema.get_table(&"post".into()).unwrap();
+    assert_eq!(post.columns[0].name, Ident::new("bar"));
+    assert_eq!(
+        post.indexes[0].to_string(),
+        "CREATE INDEX post_index_foo ON post(bar)"
+    );
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+#[index(columns(name, unique), unique)]
+struct Badge {
+    #[index(columns(unique), name = "badge_by_name")]
+    name: String,
+    #[model(column = "is_unique")]
+    unique: bool,
+}
+
+#[test]
+fn index_fields_can_be_named_like_options() {
+    let generator = Generator::new(PostgreSQL).format(None);
+    let migrations = generator
+        .generate(vec![generator.target::<Badge>()])
+        .unwrap();
+    assert_eq!(
+        migrations[0].up_sql(),
+        "CREATE TABLE badge (name TEXT NOT NULL, is_unique BOOLEAN NOT NULL);\n\n\
+        CREATE UNIQUE INDEX badge_index_name_is_unique ON badge(name,is_unique);\n\n\
+        CREATE INDEX badge_by_name ON badge(name,is_unique);\n\n"
+    );
+}
+
+#[test]
+fn dropped_columns_drop_or_shrink_indexes() {
+    let indexes = |dialect| {
+        let settings = crate::settings::Settings {
+            format: None,
+            ..crate::settings::Settings::new(dialect)
+        };
+        crate::settings::scope(settings, || {
+            let schema = Schema::_from_sql(
+                "CREATE TABLE post (author INT NOT NULL, title TEXT NOT NULL);
+                CREATE INDEX post_index_author_title ON post (author, title);
+                ALTER TABLE post DROP COLUMN title;",
+            )
+            .unwrap();
+            let post = schema.get_table(&"post".into()).unwrap();
+            post.indexes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        })
+    };
+    assert!(indexes(PostgreSQL).is_empty());
+    assert_eq!(
+        indexes(MySQL),
+        ["CREATE INDEX post_index_author_title ON post(author)"]
+    );
+}
+
+fn product_with_check(expr: &str) -> Table {
+    let mut target = Table::new("product");
+    target
+        .columns
+        .push(Column::new("price", DataType::Int(None), false));
+    target
+        .constraints
+        .push(constraint::check("product_check_price", expr));
+    target
+}
+
+#[test]
+fn unchanged_check() {
+    let stmts = migrate(
+        "CREATE TABLE product (
+            price INT NOT NULL,
+            CONSTRAINT product_check_price CHECK ((PRICE > 0))
+        );",
+        &product_with_check("price > 0"),
+    );
+    assert!(stmts.is_empty());
+}
+
+#[test]
+fn update_check() {
+    let stmts = migrate(
+        "CREATE TABLE product (
+            price INT NOT NULL,
+            CONSTRAINT product_check_price CHECK (price > 0)
+        );",
+        &product_with_check("price >= 0"),
+    );
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TABLE product DROP CONSTRAINT product_check_price CASCADE",
+            "ALTER TABLE product ADD CONSTRAINT product_check_price CHECK (price >= 0)"
+        ]
+    );
+}
+
+#[test]
+fn nested_checks_are_normalized() {
+    let stmts = migrate(
+        "CREATE TABLE product (
+            price INT NOT NULL,
+            CONSTRAINT product_check_price CHECK ((Price > 0) AND (LOWER(price) < 10))
+        );",
+        &product_with_check("price > 0 and lower((price)) < 10"),
+    );
+    assert!(stmts.is_empty());
+
+    // parentheses that change the meaning of the expression are kept.
+    let stmts = migrate(
+        "CREATE TABLE product (
+            price INT NOT NULL,
+            CONSTRAINT product_check_price CHECK ((price + 1) * 2 > 0)
+        );",
+        &product_with_check("price + 1 * 2 > 0"),
+    );
+    assert_eq!(stmts.len(), 2);
+}
+
+#[test]
+fn check_literals_are_case_sensitive() {
+    let mut target = Table::new("account");
+    target
+        .columns
+        .push(Column::new("status", DataType::Text, false));
+    target.constraints.push(constraint::check(
+        "account_check_status",
+        "status = 'active'",
+    ));
+    let stmts = migrate(
+        "CREATE TABLE account (
+            status TEXT NOT NULL,
+            CONSTRAINT account_check_status CHECK (STATUS = 'Active')
+        );",
+        &target,
+    );
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TABLE account DROP CONSTRAINT account_check_status CASCADE",
+            "ALTER TABLE account ADD CONSTRAINT account_check_status CHECK (status = 'active')"
+        ]
+    );
+}
+
+#[test]
+fn alter_column_in_place() {
+    let mut target = Table::new("post");
+    target.columns.push(Column::new_with_default(
+        "title",
+        DataType::Varchar(Some(255)),
+        true,
+        "'untitled'",
+    ));
+
+    let stmts = migrate("CREATE TABLE post (title TEXT NOT NULL);", &target);
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TABLE post ALTER COLUMN title SET DATA TYPE VARCHAR(255) \
+            USING CAST(\"title\" AS VARCHAR(255))",
+            "ALTER TABLE post ALTER COLUMN title SET DEFAULT 'untitled'",
+            "ALTER TABLE post ALTER COLUMN title DROP NOT NULL"
+        ]
+    );
+}
+
+#[test]
+fn alter_column_without_implicit_cast() {
+    let mut target = Table::new("post");
+    target
+        .columns
+        .push(Column::new("views", DataType::Int(None), false));
+
+    let stmts = migrate("CREATE TABLE post (views TEXT NOT NULL);", &target);
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TABLE post ALTER COLUMN views SET DATA TYPE INTEGER \
+            USING CAST(\"views\" AS INTEGER)"
+        ]
+    );
+
+    let stmts = migrate(
+        "CREATE TABLE post (views TEXT NOT NULL);
+        ALTER TABLE post ALTER COLUMN views SET DATA TYPE INTEGER USING CAST(\"views\" AS INTEGER);",
+        &target,
+    );
+    assert!(stmts.is_empty());
+}
+
+#[test]
+fn altered_columns_are_replayed() {
+    let mut target = Table::new("post");
+    target
+        .columns
+        .push(Column::new("title", DataType::Varchar(Some(255)), true));
+
+    let stmts = migrate(
+        "CREATE TABLE post (title TEXT NOT NULL DEFAULT 'untitled');
+        ALTER TABLE post ALTER COLUMN title DROP DEFAULT;
+        ALTER TABLE post ALTER COLUMN title TYPE VARCHAR(255);
+        ALTER TABLE post ALTER COLUMN title DROP NOT NULL;",
+        &target,
+    );
+    assert!(stmts.is_empty());
+}
+
+#[test]
+fn differences_are_reported() {
+    init();
+    let schema = Schema::_from_sql("CREATE TABLE post (author INT NOT NULL);").unwrap();
+    let mut target = post_with_author();
+    target
+        .columns
+        .push(Column::new("title", DataType::Text, false));
+
+    let settings = crate::settings::Settings {
+        format: None,
+        ..crate::settings::Settings::new(PostgreSQL)
+    };
+    let differences = crate::settings::scope(settings, || {
+        let migrations = Actions::new(&schema, &target)
+            .unwrap()
+            .as_migrations()
+            .unwrap();
+        migrations
+            .iter()
+            .filter_map(Migration::difference)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(
+        serde_json::to_string(&differences).unwrap(),
+        r#"[["post",["ALTER TABLE post ADD COLUMN title TEXT NOT NULL"],[]]]"#
+    );
+}
+
+/// a migrations directory with a single migration.
+/// It is removed when the returned value is dropped.
+fn migrations_dir(sql: &str) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("1_post.sql"), sql).unwrap();
+    dir
+}
+
+fn post_with_title() -> Table {
+    let mut target = Table::new("post");
+    target
+        .columns
+        .push(Column::new("title", DataType::Varchar(Some(255)), false));
+    target
+}
+
+#[test]
+fn generator_dialects() {
+    let dir = migrations_dir("CREATE TABLE post (title TEXT NOT NULL);");
+    let up = |dialect| {
+        let generator = Generator::new(dialect)
+            .migrations_dir(dir.path().to_str().unwrap())
+            .format(None);
+        let migrations = generator.generate(vec![post_with_title()]).unwrap();
+        migrations[0].up_sql()
+    };
+    assert_eq!(
+        up(PostgreSQL),
+        "ALTER TABLE post ALTER COLUMN title SET DATA TYPE VARCHAR(255) \
+        USING CAST(\"title\" AS VARCHAR(255));\n\n"
+    );
+    assert_eq!(
+        up(MySQL),
+        "ALTER TABLE post MODIFY COLUMN title VARCHAR(255) NOT NULL;\n\n"
+    );
+    assert!(up(SQLite).starts_with("CREATE TABLE temp"));
+}
+
+#[test]
+fn generator_filter_tables() {
+    let generator = Generator::new(PostgreSQL)
+        .reversible(true)
+        .filter_tables(vec!["post"]);
+    let migrations = generator
+        .generate(vec![post_with_title(), Table::new("author")])
+        .unwrap();
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name(), "post");
+    assert_eq!(migrations[0].down()[0].to_string(), "DROP TABLE post CASCADE");
+}
+
+#[test]
+fn dry_run() {
+    let dir = migrations_dir("CREATE TABLE post (author INT NOT NULL);");
+    let mut target = post_with_author();
+    target
+        .columns
+        .push(Column::new("title", DataType::Text, false));
+    let generator = Generator::new(PostgreSQL)
+        .migrations_dir(dir.path().to_str().unwrap())
+        .format(None)
+        .reversible(true);
+
+    assert_eq!(
+        generator.dry_run(vec![target.clone()]).unwrap(),
+        "-- post\n\
+        ALTER TABLE post ADD COLUMN title TEXT NOT NULL;\n\
+        -- post (down)\n\
+        ALTER TABLE post DROP COLUMN title CASCADE;\n"
+    );
+    assert_eq!(
+        generator.dry_run_json(vec![target]).unwrap(),
+        r#"[["post",["ALTER TABLE post ADD COLUMN title TEXT NOT NULL"],["ALTER TABLE post DROP COLUMN title CASCADE"]]]"#
+    );
+}
+
+#[allow(dead_code)]
+#[derive(crate::SqlEnum)]
+#[model(rename_all = "snake_case")]
+enum Mood {
+    Sad,
+    ItsOk,
+}
+
+#[allow(dead_code)]
+#[derive(crate::SqlEnum)]
+enum TaskState {
+    InProgress,
+    r#Done,
+}
+
+#[test]
+fn sql_enum_names() {
+    assert_eq!(<Mood as SqlEnum>::NAME, "mood");
+    assert_eq!(<Mood as SqlEnum>::VARIANTS, ["sad", "its_ok"]);
+    assert_eq!(<TaskState as SqlEnum>::NAME, "taskstate");
+    assert_eq!(<TaskState as SqlEnum>::VARIANTS, ["inprogress", "done"]);
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Person {
+    mood: Mood,
+}
+
+#[test]
+fn sql_enum_dialects() {
+    let migrations = |dialect| {
+        let generator = Generator::new(dialect).format(None).reversible(true);
+        generator.generate(vec![generator.target::<Person>()]).unwrap()
+    };
+    let postgres = migrations(PostgreSQL);
+    assert_eq!(
+        postgres[0].up_sql(),
+        "CREATE TYPE mood AS ENUM ('sad', 'its_ok');\n\n\
+        CREATE TABLE person (mood mood NOT NULL);\n\n"
+    );
+    assert_eq!(
+        postgres[0].down_sql(),
+        "DROP TABLE person CASCADE;\n\nDROP TYPE mood;\n\n"
+    );
+    assert_eq!(
+        migrations(MySQL)[0].up_sql(),
+        "CREATE TABLE person (mood ENUM('sad', 'its_ok') NOT NULL);\n\n"
+    );
+    assert_eq!(
+        migrations(SQLite)[0].up_sql(),
+        "CREATE TABLE person (mood TEXT NOT NULL, \
+        CONSTRAINT person_enum_mood CHECK (\"mood\" IN ('sad', 'its_ok')));\n\n"
+    );
+    let dir = migrations_dir(&migrations(SQLite)[0].up_sql());
+    let generator = Generator::new(SQLite).migrations_dir(dir.path().to_str().unwrap());
+    let dry_run = generator.dry_run(vec![generator.target::<Person>()]);
+    assert_eq!(dry_run.unwrap(), "");
+}
+
+fn person_with_mood(labels: &[&str]) -> Table {
+    let mut target = Table::new("person");
+    target
+        .columns
+        .push(Column::new("mood", DataType::custom("mood"), false));
+    target.types.push(CreateType {
+        name: ObjectName(vec![Ident::new("mood")]),
+        labels: labels.iter().map(ToString::to_string).collect(),
+    });
+    target
+}
+
+#[test]
+fn add_enum_values() {
+    let stmts = migrate(
+        "CREATE TYPE mood AS ENUM ('sad', 'happy');
+        CREATE TABLE person (mood mood NOT NULL);",
+        &person_with_mood(&["meh", "sad", "ok", "happy", "ecstatic"]),
+    );
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TYPE mood ADD VALUE 'meh' BEFORE 'sad'",
+            "ALTER TYPE mood ADD VALUE 'ok' AFTER 'sad'",
+            "ALTER TYPE mood ADD VALUE 'ecstatic'"
+        ]
+    );
+}
+
+#[test]
+fn remove_enum_value() {
+    let stmts = migrate(
+        "CREATE TYPE mood AS ENUM ('sad', 'happy');
+        CREATE TABLE person (mood mood NOT NULL);",
+        &person_with_mood(&["happy"]),
+    );
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TYPE mood RENAME TO mood_old",
+            "CREATE TYPE mood AS ENUM ('happy')",
+            "ALTER TABLE person ALTER COLUMN mood SET DATA TYPE mood USING CAST(CAST(mood AS TEXT) AS mood)",
+            "DROP TYPE mood_old"
+        ]
+    );
+}
+
+#[test]
+fn altered_enums_are_replayed() {
+    let stmts = migrate(
+        "CREATE TYPE mood AS ENUM ('sad', 'happy');
+        CREATE TABLE person (mood mood NOT NULL);
+        ALTER TYPE mood ADD VALUE 'ok' AFTER 'sad';
+        ALTER TYPE mood RENAME TO mood_old;
+        CREATE TYPE mood AS ENUM ('ok', 'happy');
+        ALTER TABLE person ALTER COLUMN mood TYPE mood USING mood::text::mood;
+        DROP TYPE mood_old;",
+        &person_with_mood(&["ok", "happy"]),
+    );
+    assert!(stmts.is_empty());
+}
+
+#[test]
+fn option_is_nullable() {
+    let nullable = [
+        <Option<VarChar<255>> as IntoSQL>::IS_NULLABLE,
+        <Option<i32> as IntoSQL>::IS_NULLABLE,
+        <VarChar<255> as IntoSQL>::IS_NULLABLE,
+    ];
+    assert_eq!(nullable, [true, true, false]);
+}
+
+#[test]
+fn column_becomes_not_null() {
+    let stmts = migrate("CREATE TABLE post (title TEXT);", &post_with_title());
+    assert_eq!(
+        stmts,
+        [
+            "UPDATE post SET title = '' WHERE title IS NULL",
+            "ALTER TABLE post ALTER COLUMN title SET DATA TYPE VARCHAR(255) \
+            USING CAST(\"title\" AS VARCHAR(255))",
+            "ALTER TABLE post ALTER COLUMN title SET NOT NULL"
+        ]
+    );
+
+    let mut target = Table::new("post");
+    target.columns.push(Column::new_with_default(
+        "title",
+        DataType::Text,
+        false,
+        "'untitled'",
+    ));
+    let stmts = migrate("CREATE TABLE post (title TEXT);", &target);
+    assert_eq!(
+        stmts,
+        [
+            "UPDATE post SET title = 'untitled' WHERE title IS NULL",
+            "ALTER TABLE post ALTER COLUMN title SET DEFAULT 'untitled'",
+            "ALTER TABLE post ALTER COLUMN title SET NOT NULL"
+        ]
+    );
+}
+
+#[test]
+fn column_without_zero_value_needs_default() {
+    init();
+    let schema = Schema::_from_sql("CREATE TABLE post (published TIMESTAMP);").unwrap();
+    let mut target = Table::new("post");
+    target
+        .columns
+        .push(Column::new("published", DataType::Timestamp(false), false));
+    assert_eq!(
+        Actions::new(&schema, &target).unwrap_err().to_string(),
+        "the column \"published\" of type TIMESTAMP becomes NOT NULL, \
+        but there is no value to fill its null rows with. \
+        help: consider adding a `#[default(...)]` to the field."
+    );
+}
+
+#[test]
+fn column_becomes_not_null_in_move() {
+    let dir = migrations_dir("CREATE TABLE post (title TEXT);");
+    let generator = Generator::new(SQLite)
+        .migrations_dir(dir.path().to_str().unwrap())
+        .format(None);
+    let migrations = generator.generate(vec![post_with_title()]).unwrap();
+    assert!(migrations[0]
+        .up_sql()
+        .starts_with("UPDATE post SET title = '' WHERE title IS NULL;\n\nCREATE TABLE temp"));
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Session {
+    id: uuid::Uuid,
+    token: uuid::fmt::Hyphenated,
+}
+
+#[test]
+fn uuid_dialects() {
+    let up = |dialect| up_sql::<Session>(Generator::new(dialect).format(None));
+    assert_eq!(
+        up(PostgreSQL),
+        "CREATE TABLE session (id UUID NOT NULL, token UUID NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(MySQL),
+        "CREATE TABLE session (id BINARY(16) NOT NULL, token CHAR(36) NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(SQLite),
+        "CREATE TABLE session (id BLOB NOT NULL, token TEXT NOT NULL);\n\n"
+    );
+}
+
+#[test]
+fn function_default() {
+    init();
+    let mut target = Table::new("session");
+    target.columns.push(Column::new_with_default(
+        "id",
+        DataType::Uuid,
+        false,
+        "gen_random_uuid()",
+    ));
+    let stmts = migrate("CREATE TABLE session (id UUID NOT NULL);", &target);
+    assert_eq!(
+        stmts,
+        ["ALTER TABLE session ALTER COLUMN id SET DEFAULT gen_random_uuid()"]
+    );
+
+    let stmts = migrate(
+        "CREATE TABLE session (id UUID DEFAULT gen_random_uuid() NOT NULL);",
+        &target,
+    );
+    assert!(stmts.is_empty());
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Visit {
+    #[default(lower(upper('a'), 'b'))]
+    call: String,
+    #[default(sql = "lower(upper('a'), 'b')")]
+    sql: String,
+}
+
+#[test]
+fn function_default_is_short_for_sql() {
+    let generator = Generator::new(PostgreSQL).format(None);
+    let migrations = generator
+        .generate(vec![generator.target::<Visit>()])
+        .unwrap();
+    assert_eq!(
+        migrations[0].up_sql(),
+        "CREATE TABLE visit (call TEXT DEFAULT lower(upper('a'), 'b') NOT NULL, \
+        sql TEXT DEFAULT lower(upper('a'), 'b') NOT NULL);\n\n"
+    );
+}
+
+#[test]
+fn decimal_precision_change() {
+    let mut target = Table::new("invoice");
+    target.columns.push(Column::new(
+        "total",
+        <Decimal<12, 2, rust_decimal::Decimal>>::into_sql(),
+        false,
+    ));
+    let stmts = migrate("CREATE TABLE invoice (total NUMERIC(12, 2) NOT NULL);", &target);
+    assert!(stmts.is_empty());
+
+    let stmts = migrate("CREATE TABLE invoice (total NUMERIC(10, 2) NOT NULL);", &target);
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TABLE invoice ALTER COLUMN total SET DATA TYPE NUMERIC(12,2) \
+            USING CAST(\"total\" AS NUMERIC(12,2))"
+        ]
+    );
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Counter {
+    small: u8,
+    big: u64,
+}
+
+#[test]
+fn unsigned_dialects() {
+    let up = up_sql::<Counter>;
+    assert_eq!(
+        up(Generator::new(MySQL).format(None)),
+        "CREATE TABLE counter (small TINYINT UNSIGNED NOT NULL, big BIGINT UNSIGNED NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(Generator::new(SQLite).format(None)),
+        "CREATE TABLE counter (small INTEGER NOT NULL, big INTEGER NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(Generator::new(PostgreSQL).format(None)),
+        "CREATE TABLE counter (small SMALLINT NOT NULL, big NUMERIC(20,0) NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(Generator::new(PostgreSQL).format(None).unsigned_checks(true)),
+        "CREATE TABLE counter (small SMALLINT NOT NULL, big NUMERIC(20,0) NOT NULL, \
+        CONSTRAINT counter_unsigned_small CHECK (\"small\" >= 0), \
+        CONSTRAINT counter_unsigned_big CHECK (\"big\" >= 0));\n\n"
+    );
+}
+
+#[test]
+fn unsigned_migrations_are_replayed() {
+    let dir = migrations_dir(
+        "CREATE TABLE counter (small TINYINT UNSIGNED NOT NULL, big BIGINT UNSIGNED NOT NULL);",
+    );
+    let generator = Generator::new(MySQL).migrations_dir(dir.path().to_str().unwrap());
+    let dry_run = generator.dry_run(vec![generator.target::<Counter>()]);
+    assert_eq!(dry_run.unwrap(), "");
+
+    let dir = migrations_dir(
+        "CREATE TABLE counter (small SMALLINT NOT NULL, big NUMERIC(20,0) NOT NULL, \
+        CONSTRAINT counter_unsigned_small CHECK (\"small\" >= 0), \
+        CONSTRAINT counter_unsigned_big CHECK (big >= 0));",
+    );
+    let generator = Generator::new(PostgreSQL)
+        .migrations_dir(dir.path().to_str().unwrap())
+        .unsigned_checks(true);
+    let dry_run = generator.dry_run(vec![generator.target::<Counter>()]);
+    assert_eq!(dry_run.unwrap(), "");
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Survey {
+    scores: Vec<i32>,
+    moods: Array<Mood>,
+}
+
+#[test]
+fn array_dialects() {
+    let up = |dialect| up_sql::<Survey>(Generator::new(dialect).format(None));
+    assert_eq!(
+        up(PostgreSQL),
+        "CREATE TYPE mood AS ENUM ('sad', 'its_ok');\n\n\
+        CREATE TABLE survey (scores INTEGER[] NOT NULL, moods mood[] NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(MySQL),
+        "CREATE TABLE survey (scores JSON NOT NULL, moods JSON NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(SQLite),
+        "CREATE TABLE survey (scores JSON NOT NULL, moods JSON NOT NULL);\n\n"
+    );
+}
+
+#[test]
+fn arrays_are_replayed() {
+    let dir = migrations_dir(
+        "CREATE TYPE mood AS ENUM ('sad', 'its_ok');
+        CREATE TABLE survey (scores int[] NOT NULL, moods mood ARRAY NOT NULL);",
+    );
+    let generator = Generator::new(PostgreSQL).migrations_dir(dir.path().to_str().unwrap());
+    let dry_run = generator.dry_run(vec![generator.target::<Survey>()]);
+    assert_eq!(dry_run.unwrap(), "");
+
+    let mut target = Table::new("survey");
+    target.columns.push(Column::new(
+        "moods",
+        DataType::Array(Box::new(DataType::custom("mood"))),
+        false,
+    ));
+    target.types = person_with_mood(&["sad"]).types;
+    let stmts = migrate(
+        "CREATE TYPE mood AS ENUM ('sad', 'happy');
+        CREATE TABLE survey (moods mood[] NOT NULL);",
+        &target,
+    );
+    assert_eq!(
+        stmts[2],
+        "ALTER TABLE survey ALTER COLUMN moods SET DATA TYPE mood[] USING CAST(CAST(moods AS TEXT[]) AS mood[])"
+    );
+}
+
+#[test]
+fn arrays_are_json_on_sqlite() {
+    use sqlx::Connection;
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let mut conn = sqlx::SqliteConnection::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let (json, array): (String, Array<i32>) = sqlx::query_as("SELECT ?1, ?1")
+            .bind(Array(vec![1, 2]))
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(json, "[1,2]");
+        assert_eq!(array, Array(vec![1, 2]));
+    });
+}
+
+#[test]
+fn time_zones_are_compared() {
+    init();
+    let mut target = Table::new("event");
+    target.columns.push(Column::new(
+        "starts_at",
+        <chrono::DateTime<chrono::Utc>>::into_sql(),
+        false,
+    ));
+    let stmts = migrate(
+        "CREATE TABLE event (starts_at TIMESTAMP WITH TIME ZONE NOT NULL);",
+        &target,
+    );
+    assert!(stmts.is_empty());
+
+    let stmts = migrate(
+        "CREATE TABLE event (starts_at TIMESTAMP NOT NULL);",
+        &target,
+    );
+    assert_eq!(
+        stmts,
+        [
+            "ALTER TABLE event ALTER COLUMN starts_at SET DATA TYPE TIMESTAMPTZ \
+            USING CAST(\"starts_at\" AS TIMESTAMPTZ)"
+        ]
+    );
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Meeting {
+    starts_at: ::time::OffsetDateTime,
+    day: ::time::Date,
+    length: ::time::Duration,
+}
+
+#[test]
+fn time_dialects() {
+    let up = |dialect| up_sql::<Meeting>(Generator::new(dialect).format(None));
+    assert_eq!(
+        up(PostgreSQL),
+        "CREATE TABLE meeting (starts_at TIMESTAMPTZ NOT NULL, day DATE NOT NULL, length INTERVAL NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(MySQL),
+        "CREATE TABLE meeting (starts_at TIMESTAMP NOT NULL, day DATE NOT NULL, length TIME NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(SQLite),
+        "CREATE TABLE meeting (starts_at DATETIME NOT NULL, day DATETIME NOT NULL, length TEXT NOT NULL);\n\n"
+    );
+}
+
+#[test]
+fn defaults_are_normalized() {
+    init();
+    let mut target = Table::new("post");
+    target.columns.push(Column::new_with_default(
+        "draft",
+        DataType::Boolean,
+        false,
+        "false",
+    ));
+    target.columns.push(Column::new_with_default(
+        "title",
+        DataType::Text,
+        false,
+        "'untitled'",
+    ));
+    target.columns.push(Column::new_with_default(
+        "created_at",
+        DataType::Timestamp(false),
+        false,
+        "CURRENT_TIMESTAMP",
+    ));
+    let stmts = migrate(
+        "CREATE TABLE post (
+            draft BOOLEAN DEFAULT (0) NOT NULL,
+            title TEXT DEFAULT 'untitled'::TEXT NOT NULL,
+            created_at TIMESTAMP DEFAULT current_timestamp() NOT NULL
+        );",
+        &target,
+    );
+    assert!(stmts.is_empty());
+}
+
+#[test]
+fn default_literals_are_case_sensitive() {
+    init();
+    let mut target = Table::new("post");
+    target.columns.push(Column::new_with_default(
+        "status",
+        DataType::Text,
+        false,
+        "'draft'",
+    ));
+    let stmts = migrate(
+        "CREATE TABLE post (status TEXT DEFAULT 'Draft'::TEXT NOT NULL);",
+        &target,
+    );
+    assert_eq!(
+        stmts,
+        ["ALTER TABLE post ALTER COLUMN status SET DEFAULT 'draft'"]
+    );
+}
+
+#[test]
+fn boolean_defaults_per_dialect() {
+    let up = |dialect| up_sql::<Flag>(Generator::new(dialect).format(None));
+    assert_eq!(
+        up(PostgreSQL),
+        "CREATE TABLE flag (enabled BOOLEAN DEFAULT true NOT NULL);\n\n"
+    );
+    assert_eq!(
+        up(SQLite),
+        "CREATE TABLE flag (enabled BOOLEAN DEFAULT 1 NOT NULL);\n\n"
+    );
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Flag {
+    #[default(true)]
+    enabled: bool,
+}
+
+fn rect_with_area(stored: bool) -> Table {
+    let mut target = Table::new("rect");
+    target
+        .columns
+        .push(Column::new("width", DataType::Int(None), false));
+    let mut area = Column::new("area", DataType::Int(None), true);
+    area.generated_as("width * width", stored);
+    target.columns.push(area);
+    target
+}
+
+#[test]
+fn generated_columns_are_compared() {
+    let stmts = migrate(
+        "CREATE TABLE rect (
+            width INT NOT NULL,
+            area INT GENERATED ALWAYS AS ((width * width)) STORED
+        );",
+        &rect_with_area(true),
+    );
+    assert!(stmts.is_empty());
+
+    let generator = Generator::new(PostgreSQL).format(None);
+    assert_eq!(
+        generator
+            .generate(vec![rect_with_area(false)])
+            .unwrap_err()
+            .to_string(),
+        "the column \"area\" is a virtual generated column, which is not supported by PostgreSQL. \
+        help: consider using `#[generated(\"...\", stored)]`."
+    );
+}
+
+#[test]
+fn generated_columns_are_not_copied_in_move() {
+    let dir = migrations_dir("CREATE TABLE rect (width INT NOT NULL, area INT);");
+    let generator = Generator::new(SQLite)
+        .migrations_dir(dir.path().to_str().unwrap())
+        .format(None);
+    let migrations = generator.generate(vec![rect_with_area(false)]).unwrap();
+    assert_eq!(
+        migrations[0].up_sql(),
+        "CREATE TABLE temp (\
+            width INTEGER NOT NULL, \
+            area INTEGER GENERATED ALWAYS AS (width * width) VIRTUAL\
+        );\n\n\
+        INSERT INTO temp (width) SELECT width FROM rect;\n\n\
+        DROP TABLE rect;\n\n\
+        ALTER TABLE temp RENAME TO rect;\n\n"
+    );
+}
+
+#[allow(dead_code)]
+#[derive(crate::Model)]
+struct Ticket {
+    #[primary_key]
+    id: Serial,
+    seq: i64,
+}
+
+#[test]
+fn auto_increment_dialects() {
+    let up = |dialect| up_sql::<Ticket>(Generator::new(dialect).format(None));
+    assert_eq!(
+        up(PostgreSQL),
+        "CREATE TABLE ticket (id SERIAL NOT NULL, seq BIGINT NOT NULL, \
+        CONSTRAINT ticket_primary_id PRIMARY KEY (id));\n\n"
+    );
+    assert_eq!(
+        up(MySQL),
+        "CREATE TABLE ticket (id INTEGER NOT NULL AUTO_INCREMENT, seq BIGINT NOT NULL, \
+        CONSTRAINT ticket_primary_id PRIMARY KEY (id));\n\n"
+    );
+    assert_eq!(
+        up(SQLite),
+        "CREATE TABLE ticket (id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT, seq INTEGER NOT NULL);\n\n"
+    );
+}
+
+#[test]
+fn auto_increment_requires_primary_key_on_sqlite() {
+    let error = |primary: &[&str]| {
+        let table = crate::settings::scope(crate::settings::Settings::new(SQLite), || {
+            let mut table = Table::new("ticket");
+            table.columns.push(Column::new("id", Serial::into_sql(), false));
+            table.columns.push(Column::new("seq", BigSerial::into_sql(), false));
+            if !primary.is_empty() {
+                table
+                    .constraints
+                    .push(constraint::primary("ticket_primary", primary));
+            }
+            Serial::register(&mut table, "id");
+            BigSerial::register(&mut table, "seq");
+            table
+        });
+        let generator = Generator::new(SQLite).format(None);
+        generator.generate(vec![table]).unwrap_err().to_string()
+    };
+    let message = "is auto-incremented, which SQLite only supports on the single primary key";
+    // neither column is a primary key.
+    assert!(error(&[]).contains(&format!("\"id\" {}", message)));
+    // the second serial column is not the primary key.
+    assert!(error(&["id"]).contains(&format!("\"seq\" {}", message)));
+    // a composite primary key cannot be auto-incremented.
+    assert!(error(&["id", "seq"]).contains(&format!("\"id\" {}", message)));
+}
+
+#[test]
+fn auto_increment_migrations_are_replayed() {
+    let replay = |dialect, sql| {
+        let dir = migrations_dir(sql);
+        let generator = Generator::new(dialect).migrations_dir(dir.path().to_str().unwrap());
+        generator.dry_run(vec![generator.target::<Ticket>()]).unwrap()
+    };
+    assert_eq!(
+        replay(
+            MySQL,
+            "CREATE TABLE ticket (id INT NOT NULL AUTO_INCREMENT, seq BIGINT NOT NULL, \
+            CONSTRAINT ticket_primary_id PRIMARY KEY (id));"
+        ),
+        ""
+    );
+    assert_eq!(
+        replay(
+            SQLite,
+            "CREATE TABLE ticket (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, seq INTEGER NOT NULL);"
+        ),
+        ""
+    );
+}
+
+fn invoice_with_comments(table: Option<&str>, total: Option<&str>) -> Table {
+    let mut target = Table::new("invoice");
+    target.comment = table.map(Into::into);
+    let mut column = Column::new("total", DataType::Int(None), false);
+    column.comment = total.map(Into::into);
+    target.columns.push(column);
+    target
+}
+
+#[test]
+fn comment_dialects() {
+    let up = |dialect| {
+        let generator = Generator::new(dialect).format(None);
+        let target = invoice_with_comments(Some("Issued invoices"), Some("Total in cents"));
+        generator.generate(vec![target]).unwrap()[0].up_sql()
+    };
+    assert_eq!(
+        up(PostgreSQL),
+        "CREATE TABLE invoice (total INTEGER NOT NULL);\n\n\
+        COMMENT ON TABLE invoice IS 'Issued invoices';\n\n\
+        COMMENT ON COLUMN invoice.total IS 'Total in cents';\n\n"
+    );
+    assert_eq!(
+        up(MySQL),
+        "CREATE TABLE invoice (total INTEGER NOT NULL COMMENT 'Total in cents') \
+        COMMENT = 'Issued invoices';\n\n"
+    );
+    assert_eq!(up(SQLite), "CREATE TABLE invoice (total INTEGER NOT NULL);\n\n");
+}
+
+#[test]
+fn comments_are_compared() {
+    let current = "CREATE TABLE invoice (total INT NOT NULL);
+        COMMENT ON TABLE invoice IS 'Issued invoices';
+        COMMENT ON COLUMN invoice.total IS 'Total';";
+    let stmts = migrate(
+        current,
+        &invoice_with_comments(Some("Issued invoices"), Some("Total")),
+    );
+    assert!(stmts.is_empty());
+
+    let stmts = migrate(current, &invoice_with_comments(None, Some("Total in cents")));
+    assert_eq!(
+        stmts,
+        [
+            "COMMENT ON TABLE invoice IS NULL",
+            "COMMENT ON COLUMN invoice.total IS 'Total in cents'"
+        ]
+    );
+}
+
+#[test]
+fn comments_are_replayed() {
+    let dir = migrations_dir(
+        "CREATE TABLE invoice (total INT NOT NULL COMMENT 'Total') COMMENT = 'Issued invoices';",
+    );
+    let generator = Generator::new(MySQL)
+        .format(None)
+        .migrations_dir(dir.path().to_str().unwrap());
+    let dry_run = |target| generator.dry_run(vec![target]).unwrap();
+    assert_eq!(
+        dry_run(invoice_with_comments(Some("Issued invoices"), Some("Total"))),
+        ""
+    );
+    assert_eq!(
+        dry_run(invoice_with_comments(None, Some("Total in cents"))),
+        "-- invoice\n\
+        ALTER TABLE invoice COMMENT = '';\n\
+        ALTER TABLE invoice MODIFY COLUMN total INTEGER NOT NULL COMMENT 'Total in cents';\n"
+    );
+}
+
+/// the definition of a column read from the catalog, along with its comment.
+fn introspected(col: Result<Column>) -> String {
+    let col = col.unwrap();
+    let mut out = format!("{} {}", col.name, col.r#type);
+    for option in &col.options {
+        out += &format!(" {}", option.option);
+    }
+    if let Some(comment) = &col.comment {
+        out += &format!(" -- {}", comment);
+    }
+    out
+}
+
+#[test]
+fn introspected_postgres_columns() {
+    use crate::private::scheduler::driver::introspect::postgres::{self, strip_cast};
+    assert_eq!(strip_cast("'draft'::text"), "'draft'");
+    assert_eq!(strip_cast("'it''s'::character varying"), "'it''s'");
+    assert_eq!(strip_cast("'{}'::integer[]"), "'{}'");
+    assert_eq!(strip_cast("now()"), "now()");
+    assert_eq!(strip_cast("('a'::text || 'b'::text)"), "('a'::text || 'b'::text)");
+
+    let column = |name: &str, ty, not_null, default, generated, comment: Option<&str>| {
+        let comment = comment.map(String::from);
+        introspected(postgres::column(
+            name.into(),
+            ty,
+            not_null,
+            default,
+            generated,
+            comment,
+        ))
+    };
+    assert_eq!(
+        column("id", "integer", true, Some("nextval('post_id_seq'::regclass)"), false, None),
+        "id SERIAL NOT NULL"
+    );
+    assert_eq!(
+        column("id", "bigint", true, Some("nextval('post_id_seq'::regclass)"), false, None),
+        "id BIGSERIAL NOT NULL"
+    );
+    assert_eq!(
+        column("status", "text", true, Some("'draft'::text"), false, Some("state")),
+        "status TEXT NOT NULL DEFAULT 'draft' -- state"
+    );
+    assert_eq!(
+        column("title", "character varying(255)", false, None, false, None),
+        "title VARCHAR(255)"
+    );
+    assert_eq!(
+        column("created_at", "timestamp with time zone", true, Some("now()"), false, None),
+        "created_at TIMESTAMPTZ NOT NULL DEFAULT now()"
+    );
+    assert_eq!(
+        column("area", "integer", false, Some("(width * height)"), true, None),
+        "area INTEGER GENERATED ALWAYS AS ((width * height)) STORED"
+    );
+    assert_eq!(
+        column("Title", "text", false, None, false, None),
+        "\"Title\" TEXT"
+    );
+}
+
+#[test]
+fn introspected_mysql_columns() {
+    use crate::private::scheduler::driver::introspect::mysql::{self, data_type};
+    assert_eq!(data_type("tinyint(1)"), "BOOLEAN");
+    assert_eq!(data_type("int(11)"), "int");
+    assert_eq!(data_type("bigint(20) unsigned"), "bigint unsigned");
+    assert_eq!(data_type("int(10) unsigned zerofill"), "int unsigned");
+    assert_eq!(data_type("varchar(255)"), "varchar(255)");
+    assert_eq!(data_type("decimal(10,2)"), "decimal(10,2)");
+
+    let column = |name: &str, ty: &str, nullable: &str, default: Option<&str>, extra: &str| {
+        introspected(mysql::column(
+            name.into(),
+            ty.into(),
+            nullable.into(),
+            default.map(String::from),
+            extra.into(),
+            None,
+            Some(String::new()),
+        ))
+    };
+    assert_eq!(
+        column("id", "int", "NO", None, "auto_increment"),
+        "id INTEGER NOT NULL AUTO_INCREMENT"
+    );
+    // literals are stored without quotes.
+    assert_eq!(
+        column("status", "varchar(10)", "NO", Some("draft"), ""),
+        "status VARCHAR(10) NOT NULL DEFAULT 'draft'"
+    );
+    assert_eq!(
+        column("status", "varchar(10)", "NO", Some("now()"), ""),
+        "status VARCHAR(10) NOT NULL DEFAULT 'now()'"
+    );
+    assert_eq!(
+        column("code", "varchar(10)", "NO", Some("42"), ""),
+        "code VARCHAR(10) NOT NULL DEFAULT '42'"
+    );
+    assert_eq!(
+        column("count", "int", "NO", Some("42"), ""),
+        "count INTEGER NOT NULL DEFAULT 42"
+    );
+    assert_eq!(
+        column("published", "tinyint(1)", "NO", Some("0"), ""),
+        "published BOOLEAN NOT NULL DEFAULT 0"
+    );
+    // expressions are marked `DEFAULT_GENERATED`.
+    assert_eq!(
+        column("token", "varchar(36)", "NO", Some("uuid()"), "DEFAULT_GENERATED"),
+        "token VARCHAR(36) NOT NULL DEFAULT uuid()"
+    );
+    assert_eq!(
+        column("created_at", "timestamp", "NO", Some("CURRENT_TIMESTAMP"), ""),
+        "created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP"
+    );
+    assert_eq!(
+        introspected(mysql::column(
+            "area".into(),
+            "int".into(),
+            "YES".into(),
+            None,
+            "STORED GENERATED".into(),
+            Some("(`width` * `height`)".into()),
+            Some("in cm".into()),
+        )),
+        "area INTEGER GENERATED ALWAYS AS ((width * height)) STORED -- in cm"
+    );
+    assert_eq!(
+        column("Order Id", "int", "YES", None, ""),
+        "`Order Id` INTEGER"
+    );
+}
@@ -0,0 +1,208 @@
+use crate::{prelude::*, types::IntoSQL};
+#[cfg(feature = "serde")]
+use serde::*;
+use sql_from_models_parser::ast::DataType;
+use std::{
+    convert::AsMut,
+    ops::{Deref, DerefMut},
+};
+
+/// Used to store a list of values in a single column.
+/// The type generated is `T[]` on PostgreSQL, and `JSON` on MySQL and SQLite,
+/// which do not have arrays.
+/// `Vec<T>` can be used directly for numbers, booleans and strings.
+/// With sqlx, `Array<T>` is encoded as JSON on MySQL and SQLite, while `Vec<T>`
+/// has to be wrapped in `sqlx::types::Json` there.
+/// ```
+/// use sql_from_models::{Array, Model, VarChar};
+/// #[derive(Model)]
+/// struct Post {
+///     tags: Array<VarChar<32>>,
+///     scores: Vec<i32>,
+/// }
+/// ```
+/// The previous structure would generate on PostgreSQL:
+/// ```sql
+/// CREATE TABLE post (
+///     tags VARCHAR(32)[] NOT NULL,
+///     scores INTEGER[] NOT NULL
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Default, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Array<T>(pub Vec<T>);
+
+impl<T> Array<T> {
+    pub fn new() -> Self {
+        Array(vec![])
+    }
+}
+
+impl<T> Deref for Array<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Array<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<Vec<T>> for Array<T> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> AsMut<Vec<T>> for Array<T> {
+    fn as_mut(&mut self) -> &mut Vec<T> {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for Array<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Array(vec)
+    }
+}
+
+impl<T: IntoSQL> IntoSQL for Array<T> {
+    fn into_sql() -> DataType {
+        array_of::<T>()
+    }
+
+    fn register(table: &mut Table, column: &str) {
+        register_element::<T>(table, column)
+    }
+}
+
+macro_rules! vec_into_sql {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoSQL for Vec<$ty> {
+                fn into_sql() -> DataType {
+                    array_of::<$ty>()
+                }
+            }
+        )*
+    };
+}
+
+vec_into_sql!(i16, i32, i64, f32, f64, bool, String);
+
+fn array_of<T: IntoSQL>() -> DataType {
+    match dialect() {
+        PostgreSQL => DataType::Array(Box::new(T::into_sql())),
+        _ => DataType::Json,
+    }
+}
+
+/// only the types the elements depend on are added to the table,
+/// their constraints cannot be checked on the array.
+fn register_element<T: IntoSQL>(table: &mut Table, column: &str) {
+    let mut element = Table::new(&table.name());
+    T::register(&mut element, column);
+    for ty in element.types {
+        if !table.types.contains(&ty) {
+            table.types.push(ty);
+        }
+    }
+}
+
+/// arrays are native on PostgreSQL, and JSON columns on MySQL and SQLite.
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+mod sqlx_impl {
+    use super::Array;
+    #[cfg(feature = "sqlx-mysql")]
+    use sqlx::mysql::MySql;
+    #[cfg(feature = "sqlx-postgres")]
+    use sqlx::postgres::Postgres;
+    #[cfg(feature = "sqlx-sqlite")]
+    use sqlx::sqlite::Sqlite;
+    use sqlx::{
+        decode::Decode,
+        encode::{Encode, IsNull},
+        error::BoxDynError,
+        Database, Type,
+    };
+
+    #[cfg(feature = "sqlx-postgres")]
+    impl<T> Type<Postgres> for Array<T>
+    where
+        Vec<T>: Type<Postgres>,
+    {
+        fn type_info() -> <Postgres as Database>::TypeInfo {
+            <Vec<T> as Type<Postgres>>::type_info()
+        }
+        fn compatible(ty: &<Postgres as Database>::TypeInfo) -> bool {
+            <Vec<T> as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    impl<'q, T> Encode<'q, Postgres> for Array<T>
+    where
+        Vec<T>: Encode<'q, Postgres>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <Postgres as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.0.encode_by_ref(buf)
+        }
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    impl<'r, T> Decode<'r, Postgres> for Array<T>
+    where
+        Vec<T>: Decode<'r, Postgres>,
+    {
+        fn decode(value: <Postgres as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            <Vec<T> as Decode<'r, Postgres>>::decode(value).map(Array)
+        }
+    }
+
+    macro_rules! json_array {
+        ($db:ty) => {
+            impl<T> Type<$db> for Array<T> {
+                fn type_info() -> <$db as Database>::TypeInfo {
+                    <sqlx::types::Json<Vec<T>> as Type<$db>>::type_info()
+                }
+                fn compatible(ty: &<$db as Database>::TypeInfo) -> bool {
+                    <sqlx::types::Json<Vec<T>> as Type<$db>>::compatible(ty)
+                }
+            }
+
+            impl<'q, T: serde::Serialize> Encode<'q, $db> for Array<T> {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <$db as Database>::ArgumentBuffer<'q>,
+                ) -> Result<IsNull, BoxDynError> {
+                    <sqlx::types::Json<&Vec<T>> as Encode<'q, $db>>::encode(
+                        sqlx::types::Json(&self.0),
+                        buf,
+                    )
+                }
+            }
+
+            impl<'r, T: 'r + serde::Deserialize<'r>> Decode<'r, $db> for Array<T> {
+                fn decode(value: <$db as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                    let json = <sqlx::types::Json<Vec<T>> as Decode<'r, $db>>::decode(value)?;
+                    Ok(Array(json.0))
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "sqlx-mysql")]
+    json_array!(MySql);
+    #[cfg(feature = "sqlx-sqlite")]
+    json_array!(Sqlite);
+}
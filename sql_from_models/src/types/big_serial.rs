@@ -6,21 +6,30 @@ use std::ops::{Deref, DerefMut};
 use crate::prelude::*;
 
 
-/// PostgreSQL `BigSerial` type. It enables autoincrementing functionality. 
-/// Example: 
+/// Auto-incrementing integer. The column depends on the dialect:
+///
+/// | PostgreSQL  | MySQL                   | SQLite                              |
+/// |-------------|-------------------------|-------------------------------------|
+/// | `BIGSERIAL` | `BIGINT AUTO_INCREMENT` | `INTEGER PRIMARY KEY AUTOINCREMENT` |
+///
+/// MySQL requires the column to be a key. SQLite requires it to be the only `#[primary_key]` column
+/// of the table, which it replaces, and migration generation fails otherwise.
+/// Example:
 /// ```
-/// use sql_from_models::BigSerial;
+/// use sql_from_models::{Model, BigSerial};
+/// #[derive(Model)]
 /// struct Profile {
+///     #[primary_key]
 ///     id: BigSerial,
 /// }
 /// ```
-/// The previus structure would generate: 
+/// The previous structure would generate on PostgreSQL:
 /// ```sql
 /// CREATE TABLE profile (
-///     id BigSerial NOT NULL
+///     id BIGSERIAL NOT NULL,
+///     CONSTRAINT profile_primary_id PRIMARY KEY (id)
 /// );
 /// ```
-/// 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
 #[cfg_attr(feature = "sqlx", sqlx(transparent))]
@@ -63,7 +72,15 @@ impl AsRef<i64> for BigSerial {
 
 impl IntoSQL for BigSerial {
     fn into_sql() -> DataType {
-        DataType::BigSerial
+        match dialect() {
+            PostgreSQL => DataType::BigSerial,
+            // only `INTEGER PRIMARY KEY` columns can be `AUTOINCREMENT`.
+            SQLite => DataType::Int(None),
+            _ => DataType::BigInt(None),
+        }
+    }
+    fn register(table: &mut Table, column: &str) {
+        super::auto_increment(table, column)
     }
 }
 
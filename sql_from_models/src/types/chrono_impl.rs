@@ -2,37 +2,49 @@
 // |-------------------------------|-------------------------|--------------------|--------------------|
 // | `chrono::DateTime<Utc>`       | TIMESTAMP               | TIMESTAMPTZ        | DATETIME           |
 // | `chrono::DateTime<Local>`     | TIMESTAMP               | TIMESTAMPTZ        | DATETIME           |
+// | `chrono::DateTime<FixedOffset>` | TIMESTAMP             | TIMESTAMPTZ        | DATETIME           |
 // | `chrono::NaiveDateTime`       | DATETIME                | TIMESTAMP          | DATETIME           |
 // | `chrono::NaiveDate`           | DATE                    | DATE               | DATETIME           |
 // | `chrono::NaiveTime`           | TIME                    | TIME               | DATETIME           |
+// | `chrono::Duration`            | TIME                    | INTERVAL           | TEXT               |
 //
 use super::*;
-use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use sql_from_models_parser::ast::DataType;
 
 impl IntoSQL for DateTime<Utc> {
     fn into_sql() -> DataType {
-        match *DIALECT {
-            PostgreSQL => DataType::custom("TIMESTAMPTZ"),
+        match dialect() {
+            PostgreSQL => DataType::Timestamp(true),
             SQLite => DataType::custom("DATETIME"),
-            _ => DataType::Timestamp,
+            _ => DataType::Timestamp(false),
         }
     }
 }
 impl IntoSQL for DateTime<Local> {
     fn into_sql() -> DataType {
-        match *DIALECT {
-            PostgreSQL => DataType::custom("TIMESTAMPTZ"),
+        match dialect() {
+            PostgreSQL => DataType::Timestamp(true),
             SQLite => DataType::custom("DATETIME"),
-            _ => DataType::Timestamp,
+            _ => DataType::Timestamp(false),
+        }
+    }
+}
+
+impl IntoSQL for DateTime<FixedOffset> {
+    fn into_sql() -> DataType {
+        match dialect() {
+            PostgreSQL => DataType::Timestamp(true),
+            SQLite => DataType::custom("DATETIME"),
+            _ => DataType::Timestamp(false),
         }
     }
 }
 
 impl IntoSQL for NaiveDateTime {
     fn into_sql() -> DataType {
-        match *DIALECT {
-            PostgreSQL => DataType::Timestamp,
+        match dialect() {
+            PostgreSQL => DataType::Timestamp(false),
             _ => DataType::custom("DATETIME"),
         }
     }
@@ -40,7 +52,7 @@ impl IntoSQL for NaiveDateTime {
 
 impl IntoSQL for NaiveDate {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             SQLite => DataType::custom("DATETIME"),
             _ => DataType::Date,
         }
@@ -49,9 +61,15 @@ impl IntoSQL for NaiveDate {
 
 impl IntoSQL for NaiveTime {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             SQLite => DataType::custom("DATETIME"),
-            _ => DataType::Time,
+            _ => DataType::Time(false),
         }
     }
 }
+
+impl IntoSQL for Duration {
+    fn into_sql() -> DataType {
+        interval()
+    }
+}
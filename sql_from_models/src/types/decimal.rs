@@ -0,0 +1,103 @@
+use crate::{prelude::*, types::IntoSQL};
+#[cfg(feature = "serde")]
+use serde::*;
+use sql_from_models_parser::ast::DataType;
+use std::{
+    convert::AsMut,
+    ops::{Deref, DerefMut},
+};
+
+/// Used to specify that the datatype should be a `NUMERIC(P,S)`,
+/// that is, a fixed point number with `P` digits, `S` of which are
+/// after the decimal point. The value is stored in `T`, which
+/// can be a `rust_decimal::Decimal` or a `bigdecimal::BigDecimal`
+/// when the matching feature is enabled. `P` must be positive and
+/// at least `S`, which is checked at compile time.
+/// ```
+/// use sql_from_models::{Model, Decimal};
+/// #[derive(Model)]
+/// struct Invoice {
+///     total: Decimal<10, 2, rust_decimal::Decimal>
+/// }
+/// ```
+/// The previous structure would generate:
+/// ```sql
+/// CREATE TABLE invoice (
+///     total NUMERIC(10,2) NOT NULL
+/// );
+/// ```
+/// A scale larger than the precision does not compile:
+/// ```compile_fail
+/// use sql_from_models::{types::IntoSQL, Decimal};
+/// Decimal::<2, 3, rust_decimal::Decimal>::into_sql();
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(transparent))]
+#[derive(Clone, Default, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Decimal<const P: u64, const S: u64, T>(pub T);
+
+impl<const P: u64, const S: u64, T: Default> Decimal<P, S, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<const P: u64, const S: u64, T> Decimal<P, S, T> {
+    const IS_VALID: () = assert!(
+        P > 0 && S <= P,
+        "the precision of a decimal must be positive and at least its scale."
+    );
+}
+
+impl<const P: u64, const S: u64, T> Deref for Decimal<P, S, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const P: u64, const S: u64, T> DerefMut for Decimal<P, S, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const P: u64, const S: u64, T> AsRef<T> for Decimal<P, S, T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<const P: u64, const S: u64, T> AsMut<T> for Decimal<P, S, T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<const P: u64, const S: u64, T> From<T> for Decimal<P, S, T> {
+    fn from(value: T) -> Self {
+        Decimal(value)
+    }
+}
+
+impl<const P: u64, const S: u64, T> IntoSQL for Decimal<P, S, T> {
+    fn into_sql() -> DataType {
+        let () = Self::IS_VALID;
+        DataType::Decimal(Some(P), Some(S))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl IntoSQL for rust_decimal::Decimal {
+    fn into_sql() -> DataType {
+        DataType::Decimal(None, None)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl IntoSQL for bigdecimal::BigDecimal {
+    fn into_sql() -> DataType {
+        DataType::Decimal(None, None)
+    }
+}
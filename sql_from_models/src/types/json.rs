@@ -49,7 +49,6 @@ impl<T> IntoSQL for Json<T> {
     fn into_sql() -> DataType {
         DataType::Json
     }
-    const IS_NULLABLE: bool = false;
 }
 #[allow(unused_imports)]
 #[cfg(feature = "sqlx")]
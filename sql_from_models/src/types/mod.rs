@@ -8,15 +8,27 @@
 //! | `i16`            | SMALLINT      | SMALLINT                 | INTEGER             |
 //! | `i32`            | INT           | INT                      | INTEGER             |
 //! | `i64`            | BIGINT        | BIGINT                   | INTEGER             |
+//! | `u8`             | SMALLINT      | TINYINT UNSIGNED         | INTEGER             |
+//! | `u16`            | INT           | SMALLINT UNSIGNED        | INTEGER             |
+//! | `u32`            | BIGINT        | INT UNSIGNED             | INTEGER             |
+//! | `u64`            | NUMERIC(20,0) | BIGINT UNSIGNED          | INTEGER             |
 //! | `f32`            | REAL          | FLOAT                    | REAL                |
 //! | `f64`            | REAL          | REAL                     | REAL                |
 //! | `String`         | TEXT          | TEXT                     | TEXT                |
+//! | `std::time::Duration` | INTERVAL | TIME                     | TEXT                |
 //! | `VarChar<SIZE>`  | VARCHAR(SIZE) | VARCHAR(SIZE)            | TEXT                |
 //! | `VarBinary<SIZE>`| BYTEA         | VARBINARY(SIZE)          | BLOB                |
+//! | `Decimal<P, S, T>` | NUMERIC(P,S) | NUMERIC(P,S)            | NUMERIC(P,S)        |
 //! | `Vec<u8>`        | BYTEA         | BLOB                     | BLOB                |
 //! | `[u8; SIZE]`     | BYTEA         | BLOB(SIZE)               | BLOB                |
+//! | `Vec<T>`, `Array<T>` | T[]       | JSON                     | JSON                |
+//! | `#[derive(SqlEnum)]` | CREATE TYPE ... AS ENUM | ENUM(...)       | TEXT                |
 //! |
 //!
+//! PostgreSQL has no unsigned integers, so they are stored in a wider type.
+//! `Generator::unsigned_checks` or the `--unsigned-checks` flag of the CLI also add
+//! a `CHECK (col >= 0)` constraint to their columns.
+//!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
 //! Requires the `chrono` Cargo feature flag.
@@ -25,25 +37,77 @@
 //! |------------------------------|------------------|------------------|--------------------|
 //! | `chrono::DateTime<Utc>`      | TIMESTAMPTZ      | TIMESTAMP        | DATETIME           |
 //! | `chrono::DateTime<Local>`    | TIMESTAMPTZ      | TIMESTAMP        | DATETIME           |
+//! | `chrono::DateTime<FixedOffset>` | TIMESTAMPTZ   | TIMESTAMP        | DATETIME           |
 //! | `chrono::NaiveDateTime`      | TIMESTAMP        | DATETIME         | DATETIME           |
 //! | `chrono::NaiveDate`          | DATE             | DATE             | DATETIME           |
 //! | `chrono::NaiveTime`          | TIME             | TIME             | DATETIME           |
+//! | `chrono::Duration`           | INTERVAL         | TIME             | TEXT               |
+//!
+//! ### [`time`](https://crates.io/crates/time)
+//!
+//! Requires the `time` Cargo feature flag.
+//!
+//! | Rust type                    | Postgres         | MySQL            | SQLite             |
+//! |------------------------------|------------------|------------------|--------------------|
+//! | `time::OffsetDateTime`       | TIMESTAMPTZ      | TIMESTAMP        | DATETIME           |
+//! | `time::PrimitiveDateTime`    | TIMESTAMP        | DATETIME         | DATETIME           |
+//! | `time::Date`                 | DATE             | DATE             | DATETIME           |
+//! | `time::Time`                 | TIME             | TIME             | DATETIME           |
+//! | `time::Duration`             | INTERVAL         | TIME             | TEXT               |
+//!
+//! ### [`rust_decimal`](https://crates.io/crates/rust_decimal) and [`bigdecimal`](https://crates.io/crates/bigdecimal)
 //!
+//! Requires the `rust_decimal` or `bigdecimal` Cargo feature flag.
+//! Use them inside `Decimal<P, S, T>` to set the precision and scale of the column.
+//!
+//! | Rust type                    | Postgres         | MySQL            | SQLite             |
+//! |------------------------------|------------------|------------------|--------------------|
+//! | `rust_decimal::Decimal`      | NUMERIC          | NUMERIC          | NUMERIC            |
+//! | `bigdecimal::BigDecimal`     | NUMERIC          | NUMERIC          | NUMERIC            |
+//!
+//! ### [`uuid`](https://crates.io/crates/uuid)
+//!
+//! Requires the `uuid` Cargo feature flag.
+//!
+//! | Rust type                    | Postgres         | MySQL            | SQLite             |
+//! |------------------------------|------------------|------------------|--------------------|
+//! | `uuid::Uuid`                 | UUID             | BINARY(16)       | BLOB               |
+//! | `uuid::fmt::Hyphenated`      | UUID             | CHAR(36)         | TEXT               |
+//!
+//! ```
+//! use sql_from_models::Model;
+//! #[derive(Model)]
+//! struct Session {
+//!     #[default(gen_random_uuid())]
+//!     id: uuid::Uuid,
+//! }
+//! ```
+//!
+mod array;
 #[cfg(feature = "chrono")]
 mod chrono_impl;
 #[cfg(feature = "json")]
 mod json;
 mod serial;
+mod sql_enum;
 mod time;
+#[cfg(feature = "time")]
+mod time_impl;
+#[cfg(feature = "uuid")]
+mod uuid_impl;
 mod var_binary;
 mod var_char;
 mod big_serial;
+mod decimal;
 
 #[cfg(feature = "json")]
 pub use json::*;
+pub use array::Array;
 use sql_from_models_parser::ast::DataType;
 pub use serial::Serial;
+pub use sql_enum::SqlEnum;
 pub use big_serial::BigSerial;
+pub use decimal::Decimal;
 pub use time::*;
 pub use var_binary::VarBinary;
 pub use var_char::VarChar;
@@ -56,6 +120,8 @@ use crate::prelude::*;
 pub trait IntoSQL {
     fn into_sql() -> DataType;
     const IS_NULLABLE: bool = false;
+    /// adds the objects the column depends on, such as enum types, to its table.
+    fn register(_table: &mut Table, _column: &str) {}
 }
 
 impl IntoSQL for i32 {
@@ -65,7 +131,7 @@ impl IntoSQL for i32 {
 }
 impl IntoSQL for i16 {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             SQLite => DataType::Int(None),
             PostgreSQL => DataType::SmallInt(None),
             _ => DataType::SmallInt(None),
@@ -74,7 +140,7 @@ impl IntoSQL for i16 {
 }
 impl IntoSQL for i8 {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             SQLite => DataType::Int(None),
             PostgreSQL => DataType::SmallInt(None),
             _ => DataType::TinyInt(None),
@@ -82,41 +148,131 @@ impl IntoSQL for i8 {
     }
 }
 
+impl IntoSQL for u8 {
+    fn into_sql() -> DataType {
+        match dialect() {
+            MySQL => DataType::UnsignedTinyInt(None),
+            PostgreSQL => DataType::SmallInt(None),
+            _ => DataType::Int(None),
+        }
+    }
+    fn register(table: &mut Table, column: &str) {
+        check_unsigned(table, column)
+    }
+}
+impl IntoSQL for u16 {
+    fn into_sql() -> DataType {
+        match dialect() {
+            MySQL => DataType::UnsignedSmallInt(None),
+            _ => DataType::Int(None),
+        }
+    }
+    fn register(table: &mut Table, column: &str) {
+        check_unsigned(table, column)
+    }
+}
 impl IntoSQL for u32 {
     fn into_sql() -> DataType {
-        match *DIALECT {
-            MySQL => DataType::BigInt(None),
+        match dialect() {
+            MySQL => DataType::UnsignedInt(None),
             PostgreSQL => DataType::BigInt(None),
             _ => DataType::Int(None),
         }
     }
+    fn register(table: &mut Table, column: &str) {
+        check_unsigned(table, column)
+    }
+}
+impl IntoSQL for u64 {
+    fn into_sql() -> DataType {
+        match dialect() {
+            MySQL => DataType::UnsignedBigInt(None),
+            // BIGINT cannot hold the upper half of u64.
+            PostgreSQL => DataType::Decimal(Some(20), Some(0)),
+            _ => DataType::Int(None),
+        }
+    }
+    fn register(table: &mut Table, column: &str) {
+        check_unsigned(table, column)
+    }
+}
+
+/// PostgreSQL has no unsigned types, so the sign is checked when `unsigned_checks` is set.
+fn check_unsigned(table: &mut Table, column: &str) {
+    if dialect() == PostgreSQL && settings().unsigned_checks {
+        // the column is quoted, as its name may not be a plain identifier.
+        let expr = Expr::BinaryOp(ast::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::with_quote('"', column))),
+            op: BinaryOperator::GtEq,
+            right: Box::new(Expr::Value(Value::Number("0".parse().unwrap(), false))),
+        });
+        let name = format!("{}_unsigned_{}", table.name(), column);
+        table.constraints.push(TableConstraint::Check(ast::Check {
+            name: Some(Ident::new(name)),
+            expr: Box::new(expr),
+        }));
+    }
+}
+
+/// auto-increment columns are integers with a dialect specific option outside of PostgreSQL.
+/// SQLite only accepts `AUTOINCREMENT` on the `INTEGER PRIMARY KEY` of the table,
+/// which replaces the primary key declared on the column. Any other column is
+/// left with a bare `AUTOINCREMENT`, which `Column::check_dialect` reports.
+fn auto_increment(table: &mut Table, column: &str) {
+    let keyword =
+        |keyword| ColumnOption::DialectSpecific(vec![tokenizer::Token::make_keyword(keyword)]);
+    let options = match dialect() {
+        MySQL => vec![keyword("AUTO_INCREMENT")],
+        SQLite => {
+            let primary_key = |constr: &TableConstraint| match constr {
+                TableConstraint::Unique(ast::Unique {
+                    columns,
+                    is_primary: true,
+                    ..
+                }) => Some(columns.clone()),
+                _ => None,
+            };
+            let primary_keys: Vec<_> = table.constraints.iter().filter_map(primary_key).collect();
+            match &primary_keys[..] {
+                [columns] if columns.len() == 1 && columns[0].value == column => {
+                    table.constraints.retain(|constr| primary_key(constr).is_none());
+                    vec![
+                        ColumnOption::Unique { is_primary: true },
+                        keyword("AUTOINCREMENT"),
+                    ]
+                }
+                _ => vec![keyword("AUTOINCREMENT")],
+            }
+        }
+        _ => return,
+    };
+    if let Some(col) = table.columns.iter_mut().find(|col| col.name.value == column) {
+        col.options.extend(
+            options
+                .into_iter()
+                .map(|option| ColumnOptionDef { name: None, option }),
+        );
+    }
+}
+
+impl IntoSQL for std::time::Duration {
+    fn into_sql() -> DataType {
+        interval()
+    }
+}
+
+/// durations are stored as `TIME` on MySQL, which has no intervals.
+fn interval() -> DataType {
+    match dialect() {
+        PostgreSQL => DataType::Interval,
+        MySQL => DataType::Time(false),
+        _ => DataType::Text,
+    }
 }
-// impl IntoSQL for u16 {
-//     fn into_sql() -> DataType {
-//         match *DIALECT {
-//             MySQL => DataType::Int(None),
-//             _ => DataType::Int(None),
-//         }
-//     }
-// }
-// impl IntoSQL for u8 {
-//     fn into_sql() -> DataType {
-//         match *DIALECT {
-//             MySQL => DataType::Int(None),
-//             PostgreSQL => DataType::custom("SMALLINT"),
-//             _ => DataType::Int(None),
-//         }
-//     }
-// }
 
-// impl IntoSQL for u64 {
-//     fn into_sql() -> DataType {
-//         DataType::BigInt(None)
-//     }
-// }
 impl IntoSQL for i64 {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             SQLite => DataType::Int(None),
             _ => DataType::BigInt(None),
         }
@@ -124,7 +280,7 @@ impl IntoSQL for i64 {
 }
 impl IntoSQL for f64 {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             PostgreSQL => DataType::Double,
             _ => DataType::Real,
         }
@@ -132,7 +288,7 @@ impl IntoSQL for f64 {
 }
 impl IntoSQL for f32 {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             MySQL => DataType::Real,
             _ => DataType::Real,
         }
@@ -146,7 +302,7 @@ impl IntoSQL for String {
 }
 impl<const N: usize> IntoSQL for [u8; N] {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             PostgreSQL => DataType::Bytea,
             SQLite => DataType::Blob(None),
             _ => DataType::Blob(Some(N as u64)),
@@ -155,7 +311,7 @@ impl<const N: usize> IntoSQL for [u8; N] {
 }
 impl IntoSQL for Vec<u8> {
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             PostgreSQL => DataType::Bytea,
             _ => DataType::Blob(None),
         }
@@ -166,7 +322,10 @@ impl<T: IntoSQL> IntoSQL for Option<T> {
     fn into_sql() -> DataType {
         T::into_sql()
     }
-    const IS_NULLABLE: bool = false;
+    const IS_NULLABLE: bool = true;
+    fn register(table: &mut Table, column: &str) {
+        T::register(table, column)
+    }
 }
 impl IntoSQL for bool {
     fn into_sql() -> DataType {
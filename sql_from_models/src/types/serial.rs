@@ -6,21 +6,30 @@ use std::ops::{Deref, DerefMut};
 use crate::prelude::*;
 
 
-/// PostgreSQL `SERIAL` type. It enables autoincrementing functionality. 
-/// Example: 
+/// Auto-incrementing integer. The column depends on the dialect:
+///
+/// | PostgreSQL | MySQL                | SQLite                              |
+/// |------------|----------------------|-------------------------------------|
+/// | `SERIAL`   | `INT AUTO_INCREMENT` | `INTEGER PRIMARY KEY AUTOINCREMENT` |
+///
+/// MySQL requires the column to be a key. SQLite requires it to be the only `#[primary_key]` column
+/// of the table, which it replaces, and migration generation fails otherwise.
+/// Example:
 /// ```
-/// use sql_from_models::Serial;
+/// use sql_from_models::{Model, Serial};
+/// #[derive(Model)]
 /// struct Profile {
+///     #[primary_key]
 ///     id: Serial,
 /// }
 /// ```
-/// The previus structure would generate: 
+/// The previous structure would generate on PostgreSQL:
 /// ```sql
 /// CREATE TABLE profile (
-///     id SERIAL NOT NULL
+///     id SERIAL NOT NULL,
+///     CONSTRAINT profile_primary_id PRIMARY KEY (id)
 /// );
 /// ```
-/// 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
 #[cfg_attr(feature = "sqlx", sqlx(transparent))]
@@ -63,7 +72,13 @@ impl AsRef<i32> for Serial {
 
 impl IntoSQL for Serial {
     fn into_sql() -> DataType {
-        DataType::Serial
+        match dialect() {
+            PostgreSQL => DataType::Serial,
+            _ => DataType::Int(None),
+        }
+    }
+    fn register(table: &mut Table, column: &str) {
+        super::auto_increment(table, column)
     }
 }
 
@@ -0,0 +1,96 @@
+use super::*;
+
+/// Implemented by `#[derive(SqlEnum)]` for enums stored as a native SQL enum.
+/// The column type depends on the dialect:
+///
+/// | PostgreSQL                        | MySQL                | SQLite                                |
+/// |-----------------------------------|----------------------|---------------------------------------|
+/// | `CREATE TYPE name AS ENUM (...)`  | `ENUM(...)`          | `TEXT` with `CHECK ("col" IN (...))`  |
+///
+/// ```
+/// use sql_from_models::{Model, SqlEnum};
+/// #[derive(SqlEnum)]
+/// enum Mood {
+///     Sad,
+///     Happy,
+/// }
+/// #[derive(Model)]
+/// struct Person {
+///     mood: Mood,
+/// }
+/// ```
+/// The previous structures would generate on PostgreSQL:
+/// ```sql
+/// CREATE TYPE mood AS ENUM ('sad', 'happy');
+/// CREATE TABLE person (
+///     mood mood NOT NULL
+/// );
+/// ```
+/// Variants cannot have fields:
+/// ```compile_fail
+/// use sql_from_models::SqlEnum;
+/// #[derive(SqlEnum)]
+/// enum Shape {
+///     Circle(f64),
+/// }
+/// ```
+/// and `rename_all` is the only option of the type:
+/// ```compile_fail
+/// use sql_from_models::SqlEnum;
+/// #[derive(SqlEnum)]
+/// #[model(table = "shape")]
+/// enum Shape {
+///     Circle,
+/// }
+/// ```
+pub trait SqlEnum {
+    /// name of the type in the database.
+    const NAME: &'static str;
+    /// labels of the variants, in declaration order.
+    const VARIANTS: &'static [&'static str];
+}
+
+impl<T: SqlEnum> IntoSQL for T {
+    fn into_sql() -> DataType {
+        match dialect() {
+            PostgreSQL => DataType::custom(T::NAME),
+            MySQL => DataType::Enum(labels::<T>()),
+            _ => DataType::Text,
+        }
+    }
+
+    fn register(table: &mut Table, column: &str) {
+        match dialect() {
+            PostgreSQL => {
+                let name = ObjectName(vec![Ident::new(T::NAME)]);
+                if !table.types.iter().any(|ty| ty.name == name) {
+                    table.types.push(CreateType {
+                        name,
+                        labels: labels::<T>(),
+                    });
+                }
+            }
+            SQLite => {
+                // the column is quoted, as its name may not be a plain identifier.
+                let expr = Expr::InList(ast::InList {
+                    expr: Box::new(Expr::Identifier(Ident::with_quote('"', column))),
+                    list: T::VARIANTS
+                        .iter()
+                        .map(|label| Expr::Value(Value::SingleQuotedString(label.to_string())))
+                        .collect(),
+                    negated: false,
+                });
+                let name = format!("{}_enum_{}", table.name(), column);
+                table.constraints.push(TableConstraint::Check(ast::Check {
+                    name: Some(Ident::new(name)),
+                    expr: Box::new(expr),
+                }));
+            }
+            _ => (),
+        }
+    }
+}
+
+fn labels<T: SqlEnum>() -> Vec<String> {
+    T::VARIANTS.iter().map(ToString::to_string).collect()
+}
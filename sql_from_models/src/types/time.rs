@@ -32,7 +32,6 @@ impl<T> AsMut<T> for Date<T> {
     }
 }
 impl<T> IntoSQL for Date<T> {
-    const IS_NULLABLE: bool = false;
     fn into_sql() -> DataType {
         DataType::Date
     }
@@ -65,7 +64,6 @@ impl<T> AsMut<T> for DateTime<T> {
     }
 }
 impl<T> IntoSQL for DateTime<T> {
-    const IS_NULLABLE: bool = false;
     fn into_sql() -> DataType {
         DataType::custom("DATETIME")
     }
@@ -98,8 +96,7 @@ impl<T> AsMut<T> for Timestamp<T> {
     }
 }
 impl<T> IntoSQL for Timestamp<T> {
-    const IS_NULLABLE: bool = false;
     fn into_sql() -> DataType {
-        DataType::Timestamp
+        DataType::Timestamp(false)
     }
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+// | Rust type                     | MySQL                   | Postgres           | SQLite             |
+// |-------------------------------|-------------------------|--------------------|--------------------|
+// | `time::OffsetDateTime`        | TIMESTAMP               | TIMESTAMPTZ        | DATETIME           |
+// | `time::PrimitiveDateTime`     | DATETIME                | TIMESTAMP          | DATETIME           |
+// | `time::Date`                  | DATE                    | DATE               | DATETIME           |
+// | `time::Time`                  | TIME                    | TIME               | DATETIME           |
+// | `time::Duration`              | TIME                    | INTERVAL           | TEXT               |
+//
+// `::time` is the crate, `super::time` holds the wrapper types.
+use super::*;
+use ::time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+use sql_from_models_parser::ast::DataType;
+
+impl IntoSQL for OffsetDateTime {
+    fn into_sql() -> DataType {
+        match dialect() {
+            PostgreSQL => DataType::Timestamp(true),
+            SQLite => DataType::custom("DATETIME"),
+            _ => DataType::Timestamp(false),
+        }
+    }
+}
+
+impl IntoSQL for PrimitiveDateTime {
+    fn into_sql() -> DataType {
+        match dialect() {
+            PostgreSQL => DataType::Timestamp(false),
+            _ => DataType::custom("DATETIME"),
+        }
+    }
+}
+
+impl IntoSQL for Date {
+    fn into_sql() -> DataType {
+        match dialect() {
+            SQLite => DataType::custom("DATETIME"),
+            _ => DataType::Date,
+        }
+    }
+}
+
+impl IntoSQL for Time {
+    fn into_sql() -> DataType {
+        match dialect() {
+            SQLite => DataType::custom("DATETIME"),
+            _ => DataType::Time(false),
+        }
+    }
+}
+
+impl IntoSQL for Duration {
+    fn into_sql() -> DataType {
+        interval()
+    }
+}
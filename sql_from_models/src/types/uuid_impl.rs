@@ -0,0 +1,29 @@
+// | Rust type                     | MySQL                   | Postgres           | SQLite             |
+// |-------------------------------|-------------------------|--------------------|--------------------|
+// | `uuid::Uuid`                  | BINARY(16)              | UUID               | BLOB               |
+// | `uuid::fmt::Hyphenated`       | CHAR(36)                | UUID               | TEXT               |
+//
+// These match the encodings sqlx uses for each type, so no wrapper is needed.
+use super::*;
+use sql_from_models_parser::ast::DataType;
+use uuid::{fmt::Hyphenated, Uuid};
+
+impl IntoSQL for Uuid {
+    fn into_sql() -> DataType {
+        match dialect() {
+            PostgreSQL => DataType::Uuid,
+            MySQL => DataType::Binary(16),
+            _ => DataType::Blob(None),
+        }
+    }
+}
+
+impl IntoSQL for Hyphenated {
+    fn into_sql() -> DataType {
+        match dialect() {
+            PostgreSQL => DataType::Uuid,
+            MySQL => DataType::Char(Some(36)),
+            _ => DataType::Text,
+        }
+    }
+}
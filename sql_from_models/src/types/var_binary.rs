@@ -63,9 +63,8 @@ impl<const N: u64> AsMut<Vec<u8>> for VarBinary<N> {
 }
 
 impl<const N: u64> IntoSQL for VarBinary<N> {
-    const IS_NULLABLE: bool = false;
     fn into_sql() -> DataType {
-        if !matches!(*DIALECT, SQLite) {
+        if !matches!(dialect(), SQLite) {
             DataType::Varbinary(Some(N))
         } else {
             DataType::Blob(None)
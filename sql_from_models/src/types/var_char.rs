@@ -71,9 +71,8 @@ where
 }
 
 impl<const N: u64> IntoSQL for VarChar<N> {
-    const IS_NULLABLE: bool = false;
     fn into_sql() -> DataType {
-        match *DIALECT {
+        match dialect() {
             SQLite => DataType::Text,
             _ => DataType::Varchar(Some(N)),
         }